@@ -5,7 +5,25 @@ use std::fs;
 fn main() {
     let mut comp: Compiler = Compiler::new();
     let mut errors: Vec<Error> = Vec::new();
-    for arg in env::args().skip(1) {
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        // '-D name' or '-D name=value' defines a flag for conditions
+        if let Some(flag) = arg.strip_prefix("-D") {
+            let flag: String = if flag.is_empty() {
+                let Some(flag) = args.next() else {
+                    errors.push(Error::message(String::from(
+                        "'-D' needs to be followed by the flag to define"
+                    )));
+                    break;
+                };
+                flag
+            } else { String::from(flag) };
+            match flag.split_once('=') {
+                Some((name, value)) => comp.define(name, value),
+                None => comp.define(&flag, "")
+            }
+            continue;
+        }
         match fs::read_to_string(&arg) {
             Ok(contents) => comp.parse(&arg, contents),
             Err(reason) => {
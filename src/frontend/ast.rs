@@ -1,5 +1,5 @@
 use std::fmt;
-use crate::{Compiler, PathIdx, ScopeIdx, Source, StringIdx, Type, TypeIdx};
+use crate::{Compiler, PathIdx, ScopeIdx, Source, StringIdx, TypeIdx};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum NodeType {
@@ -9,45 +9,69 @@ pub enum NodeType {
     IsExternal,
     IsExported,
     IsConstant,
+    IsVariadic,
+    DocComment,
+    Attribute,
     Block,
+    BlockValue,
     ArgumentList,
     ArgumentDecl,
+    FieldValue,
+    EnumVariant,
+    VariantPayload,
+    InterfaceList,
+    Range, InclusiveRange,
+    MatchArm, Wildcard, VariantPattern, Binding,
     UsedPath,
     // statements
     ModuleDecl,
     UsageDecl,
+    When,
+    StaticAssert,
     StructDecl,
     EnumDecl,
     InterfaceDecl,
     FunctionDecl,
     VariableDecl,
+    TupleDecl,
     Return,
     Continue,
     Break,
+    Defer,
     If,
     Loop,
     While,
+    For,
+    Match,
     Assign, AssignAdd, AssignSubtract, AssignMultiply, AssignDivide, AssignRemainder,
+    AssignBitwiseAnd, AssignBitwiseOr, AssignBitwiseXor,
+    AssignShiftLeft, AssignShiftRight,
     // expressions
     PathAccess,
     VariableAccess,
     Call,
     IntegerLiteral, FloatLiteral, StringLiteral, 
-    CStringLiteral, UnitLiteral, BooleanLiteral,
+    CStringLiteral, CharLiteral, UnitLiteral, BooleanLiteral, NullLiteral,
     MemberAccess,
+    ArrayLiteral, Index, Subslice,
+    StructLiteral,
+    TupleLiteral,
     TypeCast, SizeOf,
     AddressOf, Deref,
     Add, Subtract, Multiply, Divide, Remainder, Negate,
+    BitwiseAnd, BitwiseOr, BitwiseXor, BitwiseNot, ShiftLeft, ShiftRight,
     LessThan, GreaterThan, LessThanEqual, GreaterThanEqual, Equal, NotEqual,
     LogicalNot, LogicalAnd, LogicalOr,
+    Is,
     // types
-    PointerType, ReferenceType, 
+    PointerType, ReferenceType, NullableType, ArrayType, SliceType, TupleType,
     U8Type, U16Type, U32Type, U64Type, UsizeType,
     S8Type, S16Type, S32Type, S64Type,
     F32Type, F64Type, 
     UnitType, 
     BoolType,
-    FunctionType
+    FunctionType,
+    InferredType
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -74,6 +98,24 @@ impl AstNode {
     ) -> AstNode {
         return AstNode { t, source, value, children, rtype };
     }
+
+    pub fn documentation(&self) -> Option<StringIdx> {
+        return self.children.iter()
+            .find(|c| c.t == NodeType::DocComment)
+            .map(|c| match c.value {
+                NodeValue::String(doc) => doc,
+                _ => unreachable!("should have a value")
+            });
+    }
+
+    pub fn attribute(&self, c: &Compiler, name: &str) -> Option<&AstNode> {
+        return self.children.iter()
+            .filter(|c| c.t == NodeType::Attribute)
+            .find(|a| match a.value {
+                NodeValue::String(n) => c.strings.get(n) == name,
+                _ => unreachable!("should have a value")
+            });
+    }
 }
 
 impl fmt::Debug for AstNode {
@@ -0,0 +1,83 @@
+use crate::{AstNode, Compiler, Error, NodeType, NodeValue};
+
+pub fn included_nodes(c: &mut Compiler, nodes: &[AstNode]) -> Vec<AstNode> {
+    let mut included: Vec<AstNode> = Vec::new();
+    for node in nodes {
+        if node.t != NodeType::When {
+            included.push(node.clone());
+            continue;
+        }
+        let branch: Option<&AstNode> = match eval_condition(c, &node.children[0]) {
+            Some(true) => Some(&node.children[1]),
+            Some(false) => node.children.get(2),
+            None => None
+        };
+        if let Some(branch) = branch {
+            included.extend(included_nodes(c, &branch.children));
+        }
+    }
+    return included;
+}
+
+fn eval_condition(c: &mut Compiler, n: &AstNode) -> Option<bool> {
+    match n.t {
+        NodeType::BooleanLiteral | NodeType::PathAccess => {
+            let value: Option<String> = eval_value(c, n)?;
+            if n.t == NodeType::BooleanLiteral {
+                return Some(value.as_deref() == Some("true"));
+            }
+            return Some(value.is_some());
+        }
+        NodeType::LogicalNot => {
+            return eval_condition(c, &n.children[0]).map(|v| !v);
+        }
+        NodeType::LogicalAnd | NodeType::LogicalOr => {
+            let left: Option<bool> = eval_condition(c, &n.children[0]);
+            let right: Option<bool> = eval_condition(c, &n.children[1]);
+            if n.t == NodeType::LogicalAnd { return Some(left? && right?); }
+            return Some(left? || right?);
+        }
+        NodeType::Equal | NodeType::NotEqual => {
+            let left: Option<Option<String>> = eval_value(c, &n.children[0]);
+            let right: Option<Option<String>> = eval_value(c, &n.children[1]);
+            // undefined properties are not equal to any value
+            let is_equal: bool = match (left?, right?) {
+                (Some(left), Some(right)) => left == right,
+                _ => false
+            };
+            return Some(is_equal == (n.t == NodeType::Equal));
+        }
+        _ => {
+            report_invalid(c, n);
+            return None;
+        }
+    }
+}
+
+fn eval_value(c: &mut Compiler, n: &AstNode) -> Option<Option<String>> {
+    match (n.t, n.value) {
+        (NodeType::StringLiteral | NodeType::IntegerLiteral
+                | NodeType::BooleanLiteral, NodeValue::String(v)) => {
+            return Some(Some(String::from(c.strings.get(v))));
+        }
+        (NodeType::PathAccess, NodeValue::Path(p))
+                if n.children[0].children.is_empty() => {
+            if let [name] = c.paths.get(p) {
+                return Some(c.properties.get(c.strings.get(*name)).cloned());
+            }
+        }
+        _ => {}
+    }
+    report_invalid(c, n);
+    return None;
+}
+
+fn report_invalid(c: &mut Compiler, n: &AstNode) {
+    c.errors.push(Error::fixed(
+        concat!(
+            "conditions of a 'when' may only consist of properties, literals ",
+            "and the operators '==', '!=', '!', '&&' and '||'"
+        ),
+        n.source
+    ));
+}
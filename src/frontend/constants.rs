@@ -0,0 +1,246 @@
+use crate::{
+    integer_range, variable_type_index, AstNode, Compiler, Error,
+    NodeType, NodeValue, PathIdx, ScopeIdx, Type, Variable
+};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Constant {
+    Integer(i128),
+    Float(f64),
+    Boolean(bool)
+}
+
+// 'scopes' are the scopes of the local variables that are visible to 'n'
+pub fn eval_constant(
+    c: &Compiler, n: &AstNode, scopes: &[ScopeIdx]
+) -> Result<Constant, Error> {
+    return eval(c, n, scopes, &mut Vec::new());
+}
+
+fn eval(
+    c: &Compiler, n: &AstNode, scopes: &[ScopeIdx], stack: &mut Vec<PathIdx>
+) -> Result<Constant, Error> {
+    match (n.t, n.value) {
+        (NodeType::IntegerLiteral, NodeValue::String(v)) => {
+            // literals that do not fit are reported by the type checker
+            return c.strings.get(v).parse()
+                .map(Constant::Integer)
+                .map_err(|_| not_constant(n));
+        }
+        (NodeType::FloatLiteral, NodeValue::String(v)) => {
+            return Ok(Constant::Float(
+                c.strings.get(v).parse().unwrap_or(f64::INFINITY)
+            ));
+        }
+        (NodeType::CharLiteral, NodeValue::String(v)) => {
+            let value: char = c.strings.get(v).chars().next().unwrap_or('\0');
+            return Ok(Constant::Integer(value as i128));
+        }
+        (NodeType::BooleanLiteral, NodeValue::String(v)) => {
+            return Ok(Constant::Boolean(c.strings.get(v) == "true"));
+        }
+        // the type checker computes the size if it is known
+        (NodeType::SizeOf, NodeValue::String(size)) => {
+            return c.strings.get(size).parse()
+                .map(Constant::Integer)
+                .map_err(|_| not_constant(n));
+        }
+        (NodeType::SizeOf, _) => {
+            return Err(Error::fixed(
+                "the size of the type is not known at compile time", n.source
+            ));
+        }
+        (NodeType::PathAccess, NodeValue::Path(p)) => {
+            // local variables shadow global ones
+            let local: Option<&Variable> = match c.paths.get(p) {
+                [name] => scopes.iter().rev()
+                    .find_map(|s| c.scopes.get(*s).get_last(*name)),
+                _ => None
+            };
+            if let Some(local) = local {
+                return local.value.ok_or_else(|| not_constant(n));
+            }
+            let decl_node: Option<&AstNode> = c.symbols.symbols().get(&p)
+                .map(|s| &s.decl_node)
+                .filter(|d| d.t == NodeType::VariableDecl)
+                .filter(|d| d.children.iter()
+                    .any(|c| c.t == NodeType::IsConstant)
+                );
+            let value: Option<&AstNode> = decl_node
+                .and_then(|d| d.children.get(variable_type_index(d) + 1));
+            let Some(value) = value.filter(|_| !stack.contains(&p)) else {
+                return Err(not_constant(n));
+            };
+            stack.push(p);
+            let value: Result<Constant, Error> = eval(c, value, &[], stack);
+            stack.pop();
+            return value;
+        }
+        (NodeType::TypeCast, _) => {
+            let value: Constant = eval(c, &n.children[0], scopes, stack)?;
+            let target: &Type = c.types.get(n.rtype);
+            return match (value, integer_range(c, target)) {
+                (Constant::Integer(v), Some((min, max))) =>
+                    Ok(Constant::Integer(wrap(v, min, max))),
+                (Constant::Float(v), Some((min, max))) =>
+                    Ok(Constant::Integer((v as i128).clamp(min, max))),
+                (Constant::Boolean(v), Some(_)) => Ok(Constant::Integer(v as i128)),
+                (Constant::Integer(v), None) if target.is_float() =>
+                    Ok(Constant::Float(round(c, n, v as f64))),
+                (Constant::Float(v), None) if target.is_float() =>
+                    Ok(Constant::Float(round(c, n, v))),
+                _ => Err(not_constant(n))
+            };
+        }
+        (NodeType::Negate, _) => {
+            return match eval(c, &n.children[0], scopes, stack)? {
+                Constant::Integer(v) => fit(c, n, -v),
+                Constant::Float(v) => Ok(Constant::Float(-v)),
+                Constant::Boolean(_) => Err(not_constant(n))
+            };
+        }
+        (NodeType::BitwiseNot, _) => {
+            return match eval(c, &n.children[0], scopes, stack)? {
+                Constant::Integer(v) => match integer_range(
+                    c, c.types.get(n.rtype)
+                ) {
+                    Some((0, max)) => Ok(Constant::Integer(max - v)),
+                    _ => Ok(Constant::Integer(!v))
+                },
+                _ => Err(not_constant(n))
+            };
+        }
+        (NodeType::LogicalNot, _) => {
+            return match eval(c, &n.children[0], scopes, stack)? {
+                Constant::Boolean(v) => Ok(Constant::Boolean(!v)),
+                _ => Err(not_constant(n))
+            };
+        }
+        (NodeType::LogicalAnd | NodeType::LogicalOr, _) => {
+            let left: Constant = eval(c, &n.children[0], scopes, stack)?;
+            // the right side is only evaluated if it is needed
+            return match (n.t, left) {
+                (NodeType::LogicalAnd, Constant::Boolean(false)) |
+                (NodeType::LogicalOr, Constant::Boolean(true)) => Ok(left),
+                _ => eval(c, &n.children[1], scopes, stack)
+            };
+        }
+        _ => {}
+    }
+    let (left, right) = match &n.children[..] {
+        [left, right] if is_binary(n.t) => (
+            eval(c, left, scopes, stack)?, eval(c, right, scopes, stack)?
+        ),
+        _ => return Err(not_constant(n))
+    };
+    match (left, right) {
+        (Constant::Integer(l), Constant::Integer(r)) => {
+            let value: Option<i128> = match n.t {
+                NodeType::Add => l.checked_add(r),
+                NodeType::Subtract => l.checked_sub(r),
+                NodeType::Multiply => l.checked_mul(r),
+                NodeType::Divide | NodeType::Remainder if r == 0 => {
+                    return Err(Error::fixed(
+                        "the divisor of this division is zero", n.source
+                    ));
+                }
+                NodeType::Divide => l.checked_div(r),
+                NodeType::Remainder => l.checked_rem(r),
+                NodeType::BitwiseAnd => Some(l & r),
+                NodeType::BitwiseOr => Some(l | r),
+                NodeType::BitwiseXor => Some(l ^ r),
+                NodeType::ShiftLeft => u32::try_from(r).ok()
+                    .and_then(|r| l.checked_shl(r))
+                    .map(|v| match integer_range(c, c.types.get(n.rtype)) {
+                        Some((min, max)) => wrap(v, min, max),
+                        None => v
+                    }),
+                NodeType::ShiftRight => u32::try_from(r).ok()
+                    .and_then(|r| l.checked_shr(r)),
+                _ => return Ok(Constant::Boolean(compare(n.t, l, r)))
+            };
+            return match value {
+                Some(value) => fit(c, n, value),
+                None => Err(overflow(c, n))
+            };
+        }
+        (Constant::Float(l), Constant::Float(r)) => {
+            let value: f64 = match n.t {
+                NodeType::Add => l + r,
+                NodeType::Subtract => l - r,
+                NodeType::Multiply => l * r,
+                NodeType::Divide => l / r,
+                NodeType::Remainder => l % r,
+                NodeType::BitwiseAnd | NodeType::BitwiseOr | NodeType::BitwiseXor
+                    | NodeType::ShiftLeft | NodeType::ShiftRight => {
+                    return Err(not_constant(n));
+                }
+                _ => return Ok(Constant::Boolean(compare(n.t, l, r)))
+            };
+            return Ok(Constant::Float(round(c, n, value)));
+        }
+        (Constant::Boolean(l), Constant::Boolean(r)) => match n.t {
+            NodeType::Equal => return Ok(Constant::Boolean(l == r)),
+            NodeType::NotEqual => return Ok(Constant::Boolean(l != r)),
+            _ => return Err(not_constant(n))
+        },
+        _ => return Err(not_constant(n))
+    }
+}
+
+fn is_binary(t: NodeType) -> bool {
+    return matches!(
+        t,
+        NodeType::Add | NodeType::Subtract | NodeType::Multiply |
+        NodeType::Divide | NodeType::Remainder |
+        NodeType::BitwiseAnd | NodeType::BitwiseOr | NodeType::BitwiseXor |
+        NodeType::ShiftLeft | NodeType::ShiftRight |
+        NodeType::LessThan | NodeType::GreaterThan |
+        NodeType::LessThanEqual | NodeType::GreaterThanEqual |
+        NodeType::Equal | NodeType::NotEqual
+    );
+}
+
+fn compare<T: PartialOrd>(t: NodeType, l: T, r: T) -> bool {
+    return match t {
+        NodeType::LessThan => l < r,
+        NodeType::GreaterThan => l > r,
+        NodeType::LessThanEqual => l <= r,
+        NodeType::GreaterThanEqual => l >= r,
+        NodeType::Equal => l == r,
+        NodeType::NotEqual => l != r,
+        _ => unreachable!("should be a comparison")
+    };
+}
+
+fn wrap(value: i128, min: i128, max: i128) -> i128 {
+    return (value - min).rem_euclid(max - min + 1) + min;
+}
+
+fn round(c: &Compiler, n: &AstNode, value: f64) -> f64 {
+    if *c.types.get(n.rtype) == Type::F32 { return value as f32 as f64; }
+    return value;
+}
+
+fn fit(c: &Compiler, n: &AstNode, value: i128) -> Result<Constant, Error> {
+    if let Some((min, max)) = integer_range(c, c.types.get(n.rtype)) {
+        if value < min || value > max { return Err(overflow(c, n)); }
+    }
+    return Ok(Constant::Integer(value));
+}
+
+fn overflow(c: &Compiler, n: &AstNode) -> Error {
+    return Error::dynamic(
+        format!(
+            "the value of this expression does not fit into the type '{}'",
+            n.rtype.display(c)
+        ),
+        n.source
+    );
+}
+
+fn not_constant(n: &AstNode) -> Error {
+    return Error::fixed(
+        "the value of this expression is not known at compile time", n.source
+    );
+}
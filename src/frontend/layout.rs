@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+
+use crate::{
+    AstNode, Compiler, NodeType, NodeValue, PathIdx, StringIdx, Type,
+    TypeChecker, TypeIdx, MAX_STRUCT_DEPTH
+};
+
+fn pointer_size(tc: &TypeChecker) -> u64 {
+    return tc.comp().pointer_width() / 8;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Layout {
+    pub size: u64,
+    pub alignment: u64
+}
+
+impl Layout {
+    pub fn new(size: u64, alignment: u64) -> Layout {
+        return Layout { size, alignment };
+    }
+}
+
+fn align_up(offset: u64, alignment: u64) -> u64 {
+    return offset.div_ceil(alignment) * alignment;
+}
+
+pub fn type_layout(tc: &mut TypeChecker, n: &AstNode) -> Option<Layout> {
+    // function types are not checked yet, but are always pointers
+    if n.t == NodeType::FunctionType {
+        let size: u64 = pointer_size(tc);
+        return Some(Layout::new(size, size));
+    }
+    let t: TypeIdx = tc.check_type(n);
+    return layout(tc, t, &mut Vec::new());
+}
+
+fn declared_layout(
+    tc: &mut TypeChecker, n: &AstNode, t_args: &HashMap<StringIdx, TypeIdx>,
+    stack: &mut Vec<TypeIdx>
+) -> Option<Layout> {
+    if n.t == NodeType::FunctionType {
+        let size: u64 = pointer_size(tc);
+        return Some(Layout::new(size, size));
+    }
+    let t: TypeIdx = tc.declared_type(n, t_args.clone());
+    return layout(tc, t, stack);
+}
+
+fn layout(
+    tc: &mut TypeChecker, t: TypeIdx, stack: &mut Vec<TypeIdx>
+) -> Option<Layout> {
+    match tc.comp().types.get(t).clone() {
+        Type::Unit => return Some(Layout::new(0, 1)),
+        Type::U8 | Type::S8 | Type::Boolean => return Some(Layout::new(1, 1)),
+        Type::U16 | Type::S16 => return Some(Layout::new(2, 2)),
+        Type::U32 | Type::S32 | Type::F32 => return Some(Layout::new(4, 4)),
+        Type::U64 | Type::S64 | Type::F64 => return Some(Layout::new(8, 8)),
+        Type::Usize => {
+            return Some(Layout::new(pointer_size(tc), pointer_size(tc)));
+        }
+        Type::Pointer(_, pointee) | Type::Reference(_, pointee) => {
+            let is_interface: bool = matches!(
+                tc.comp().types.get(pointee), Type::Interface(_)
+            );
+            // pointers to interfaces also carry a pointer to the vtable
+            let size: u64 = if is_interface { pointer_size(tc) * 2 }
+                else { pointer_size(tc) };
+            return Some(Layout::new(size, pointer_size(tc)));
+        }
+        // null is represented by the address zero
+        Type::Nullable(pointer) => return layout(tc, pointer, stack),
+        Type::Slice(_, _) => {
+            return Some(Layout::new(pointer_size(tc) * 2, pointer_size(tc)));
+        }
+        Type::Array(length, element) => {
+            let element: Layout = layout(tc, element, stack)?;
+            return Some(Layout::new(
+                element.size.checked_mul(length)?, element.alignment
+            ));
+        }
+        Type::Tuple(elements) => {
+            let mut size: u64 = 0;
+            let mut alignment: u64 = 1;
+            for element in elements {
+                let element: Layout = layout(tc, element, stack)?;
+                size = align_up(size, element.alignment) + element.size;
+                alignment = alignment.max(element.alignment);
+            }
+            return Some(Layout::new(align_up(size, alignment), alignment));
+        }
+        Type::Struct(path, t_args) => {
+            return struct_layout(tc, t, path, &t_args, stack);
+        }
+        Type::Enum(path) => return enum_layout(tc, t, path, stack),
+        Type::Unknown | Type::Integer | Type::Float | Type::Null |
+        Type::Interface(_) | Type::Param(_, _) => return None
+    }
+}
+
+fn enum_layout(
+    tc: &mut TypeChecker, t: TypeIdx, path: PathIdx, stack: &mut Vec<TypeIdx>
+) -> Option<Layout> {
+    let decl_node: AstNode = tc.comp().symbols.symbols().get(&path)?
+        .decl_node.clone();
+    let backing_type: &AstNode = decl_node.children.iter()
+        .find(|c| c.t != NodeType::IsPublic && c.t != NodeType::DocComment)
+        .expect("should have a backing type");
+    let tag: Layout = declared_layout(
+        tc, backing_type, &HashMap::new(), stack
+    )?;
+    let payloads: Vec<&AstNode> = decl_node.children.iter()
+        .flat_map(|v| v.children.iter())
+        .filter(|c| c.t == NodeType::VariantPayload)
+        .collect();
+    if payloads.is_empty() { return Some(tag); }
+    // infinitely sized enums are reported by the type checker
+    if stack.contains(&t) { return None; }
+    stack.push(t);
+    // the values of all variants share the memory after the tag
+    let mut union: Layout = Layout::new(0, 1);
+    for payload in payloads {
+        let mut size: u64 = 0;
+        for value_type in &payload.children {
+            let Some(value) = declared_layout(
+                tc, value_type, &HashMap::new(), stack
+            ) else {
+                stack.pop();
+                return None;
+            };
+            size = align_up(size, value.alignment) + value.size;
+            union.alignment = union.alignment.max(value.alignment);
+        }
+        union.size = union.size.max(size);
+    }
+    stack.pop();
+    let alignment: u64 = tag.alignment.max(union.alignment);
+    let size: u64 = align_up(tag.size, union.alignment) + union.size;
+    return Some(Layout::new(align_up(size, alignment), alignment));
+}
+
+fn struct_layout(
+    tc: &mut TypeChecker, t: TypeIdx, path: PathIdx, t_args: &[TypeIdx],
+    stack: &mut Vec<TypeIdx>
+) -> Option<Layout> {
+    let symbol = tc.comp().symbols.symbols().get(&path)?;
+    if t_args.len() != symbol.template_args.len() { return None; }
+    // infinitely sized structs are reported by the type checker
+    if stack.contains(&t) || stack.len() > MAX_STRUCT_DEPTH { return None; }
+    let t_args: HashMap<StringIdx, TypeIdx> = symbol.template_args.iter()
+        .copied()
+        .zip(t_args.iter().copied())
+        .collect();
+    let decl_node: AstNode = symbol.decl_node.clone();
+    let fields: &AstNode = decl_node.children.iter()
+        .filter(|c| c.t == NodeType::ArgumentList)
+        .nth(1).expect("should have fields");
+    let is_packed: bool = decl_node.attribute(tc.comp(), "packed").is_some();
+    stack.push(t);
+    let mut size: u64 = 0;
+    let mut alignment: u64 = attribute_alignment(tc.comp(), &decl_node);
+    for field in &fields.children {
+        let field_type: &AstNode = field.children.last()
+            .expect("fields must have a type");
+        let Some(mut field_layout) = declared_layout(
+            tc, field_type, &t_args, stack
+        ) else {
+            stack.pop();
+            return None;
+        };
+        // packed structs only keep the alignments that are requested
+        if is_packed { field_layout.alignment = 1; }
+        field_layout.alignment = field_layout.alignment
+            .max(attribute_alignment(tc.comp(), field));
+        size = align_up(size, field_layout.alignment) + field_layout.size;
+        alignment = alignment.max(field_layout.alignment);
+    }
+    stack.pop();
+    return Some(Layout::new(align_up(size, alignment), alignment));
+}
+
+fn attribute_alignment(c: &Compiler, n: &AstNode) -> u64 {
+    return n.attribute(c, "align")
+        .and_then(|a| a.children.first())
+        .and_then(|a| match a.value {
+            NodeValue::String(v) => c.strings.get(v).parse().ok(),
+            _ => None
+        })
+        .unwrap_or(1);
+}
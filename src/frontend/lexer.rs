@@ -4,9 +4,9 @@ use crate::{Compiler, Error, Source, StringIdx};
 pub enum TokenType {
     EndOfFile,
     Invalid,
-    Whitespace, Comment,
+    Whitespace, Comment, DocComment,
     Integer, Float,
-    String, CString,
+    String, CString, Char,
     Identifier,
     BraceOpen, BraceClose,
     BracketOpen, BracketClose,
@@ -14,22 +14,27 @@ pub enum TokenType {
     Equal,
     Plus, Minus, Asterisk, Slash, Percent,
     PlusEqual, MinusEqual, AsteriskEqual, SlashEqual, PercentEqual,
+    AmpersandEqual, PipeEqual, CaretEqual,
+    DoubleLessThanEqual, DoubleGreaterThanEqual,
     LessThan, GreaterThan, LessThanEqual, GreaterThanEqual,
     DoubleEqual, NotEqual,
-    ExclamationMark,
+    EqualGreaterThan,
+    ExclamationMark, QuestionMark,
     DoubleAmpersand,
     DoublePipe,
     Colon, DoubleColon,
-    Comma, Semicolon,
-    Dot,
-    Ampersand,
+    Comma, Semicolon, At,
+    Dot, DoubleDot, DoubleDotEqual, TripleDot,
+    Ampersand, Pipe, Caret, Tilde,
+    DoubleLessThan, DoubleGreaterThan,
     KeywordPub, KeywordExt, KeywordExp, KeywordMod, KeywordUse,
     KeywordStruct, KeywordFun, KeywordVar, KeywordEnum, KeywordInterface,
-    KeywordIf, KeywordElse, KeywordLoop, KeywordWhile,
-    KeywordReturn, KeywordContinue, KeywordBreak,
+    KeywordIf, KeywordElse, KeywordLoop, KeywordWhile, KeywordFor, KeywordIn,
+    KeywordMatch, KeywordIs, KeywordWhen, KeywordStaticAssert,
+    KeywordReturn, KeywordContinue, KeywordBreak, KeywordDefer,
     KeywordAs, KeywordSizeof, 
     KeywordConst,
-    KeywordTrue, KeywordFalse, KeywordUnit,
+    KeywordTrue, KeywordFalse, KeywordUnit, KeywordNull,
     KeywordU8, KeywordU16, KeywordU32, KeywordU64,
     KeywordS8, KeywordS16, KeywordS32, KeywordS64,
     KeywordF32, KeywordF64, KeywordUsize, KeywordBool
@@ -49,6 +54,7 @@ impl Token {
             TokenType::Invalid => String::from("invalid character"),
             TokenType::String |
             TokenType::CString => String::from("string literal"),
+            TokenType::DocComment => String::from("documentation comment"),
             _ => format!("'{}'", c.strings.get(self.content))
         };
     }
@@ -152,19 +158,11 @@ impl Lexer {
         if self.current() == '"' {
             return self.lex_string(c, TokenType::String);
         }
+        if self.current() == '\'' {
+            return self.lex_char(c);
+        }
         if self.current().is_ascii_digit() {
-            while self.has() && self.current().is_ascii_digit() {
-                self.consume();
-            }
-            if self.has() && self.current() == '.' {
-                self.consume();
-                while self.has() && self.current().is_ascii_digit() {
-                    self.consume();
-                }
-                return self.build(c, TokenType::Float);
-            } else {
-                return self.build(c, TokenType::Integer);
-            }
+            return self.lex_number(c);
         }
         if self.current().is_ascii_alphanumeric() || self.current() == '_' {
             while self.has() 
@@ -188,15 +186,25 @@ impl Lexer {
                 "else" => return self.build(c, TokenType::KeywordElse),
                 "loop" => return self.build(c, TokenType::KeywordLoop),
                 "while" => return self.build(c, TokenType::KeywordWhile),
+                "for" => return self.build(c, TokenType::KeywordFor),
+                "in" => return self.build(c, TokenType::KeywordIn),
+                "match" => return self.build(c, TokenType::KeywordMatch),
+                "is" => return self.build(c, TokenType::KeywordIs),
+                "when" => return self.build(c, TokenType::KeywordWhen),
+                "static_assert" => return self.build(
+                    c, TokenType::KeywordStaticAssert
+                ),
                 "return" => return self.build(c, TokenType::KeywordReturn),
                 "continue" => return self.build(c, TokenType::KeywordContinue),
                 "break" => return self.build(c, TokenType::KeywordBreak),
+                "defer" => return self.build(c, TokenType::KeywordDefer),
                 "as" => return self.build(c, TokenType::KeywordAs),
                 "sizeof" => return self.build(c, TokenType::KeywordSizeof),
                 "const" => return self.build(c, TokenType::KeywordConst),
                 "true" => return self.build(c, TokenType::KeywordTrue),
                 "false" => return self.build(c, TokenType::KeywordFalse),
                 "unit" => return self.build(c, TokenType::KeywordUnit),
+                "null" => return self.build(c, TokenType::KeywordNull),
                 "u8" => return self.build(c, TokenType::KeywordU8),
                 "u16" => return self.build(c, TokenType::KeywordU16),
                 "u32" => return self.build(c, TokenType::KeywordU32),
@@ -212,25 +220,57 @@ impl Lexer {
                 _ => return self.build(c, TokenType::Identifier)
             }
         }
+        if self.current() == '/' && self.peek() == '*' {
+            return self.lex_block_comment(c);
+        }
+        if self.current() == '#' && self.peek() == '#' {
+            self.skip();
+            self.skip();
+            if self.current() == ' ' { self.skip(); }
+            while self.has() && self.current() != '\n' {
+                self.consume();
+            }
+            return self.build(c, TokenType::DocComment);
+        }
         if self.current() == '#' {
             while self.has() && self.current() != '\n' {
                 self.consume();
             }
             return self.build(c, TokenType::Comment);
         }
+        let rest: &str = &self.source[self.pos..];
+        if rest.starts_with("<<=") {
+            return self.build_s(c, "<<=", TokenType::DoubleLessThanEqual);
+        }
+        if rest.starts_with(">>=") {
+            return self.build_s(c, ">>=", TokenType::DoubleGreaterThanEqual);
+        }
+        if rest.starts_with("...") {
+            return self.build_s(c, "...", TokenType::TripleDot);
+        }
+        if rest.starts_with("..=") {
+            return self.build_s(c, "..=", TokenType::DoubleDotEqual);
+        }
         match (self.current(), self.peek()) {
             ('+', '=') => return self.build_s(c, "+=", TokenType::PlusEqual),
             ('-', '=') => return self.build_s(c, "-=", TokenType::MinusEqual),
             ('*', '=') => return self.build_s(c, "*=", TokenType::AsteriskEqual),
             ('/', '=') => return self.build_s(c, "/=", TokenType::SlashEqual),
             ('%', '=') => return self.build_s(c, "%=", TokenType::PercentEqual),
+            ('&', '=') => return self.build_s(c, "&=", TokenType::AmpersandEqual),
+            ('|', '=') => return self.build_s(c, "|=", TokenType::PipeEqual),
+            ('^', '=') => return self.build_s(c, "^=", TokenType::CaretEqual),
+            ('<', '<') => return self.build_s(c, "<<", TokenType::DoubleLessThan),
+            ('>', '>') => return self.build_s(c, ">>", TokenType::DoubleGreaterThan),
             ('<', '=') => return self.build_s(c, "<=", TokenType::LessThanEqual),
             ('>', '=') => return self.build_s(c, ">=", TokenType::GreaterThanEqual),
             ('=', '=') => return self.build_s(c, "==", TokenType::DoubleEqual),
             ('!', '=') => return self.build_s(c, "!=", TokenType::NotEqual),
+            ('=', '>') => return self.build_s(c, "=>", TokenType::EqualGreaterThan),
             ('&', '&') => return self.build_s(c, "&&", TokenType::DoubleAmpersand),
             ('|', '|') => return self.build_s(c, "||", TokenType::DoublePipe),
             (':', ':') => return self.build_s(c, "::", TokenType::DoubleColon),
+            ('.', '.') => return self.build_s(c, "..", TokenType::DoubleDot),
             _ => {}
         }
         match self.current() {
@@ -249,11 +289,16 @@ impl Lexer {
             '<' => return self.build_s(c, "<", TokenType::LessThan),
             '>' => return self.build_s(c, ">", TokenType::GreaterThan),
             '!' => return self.build_s(c, "!", TokenType::ExclamationMark),
+            '?' => return self.build_s(c, "?", TokenType::QuestionMark),
             ':' => return self.build_s(c, ":", TokenType::Colon),
             ',' => return self.build_s(c, ",", TokenType::Comma),
             ';' => return self.build_s(c, ";", TokenType::Semicolon),
+            '@' => return self.build_s(c, "@", TokenType::At),
             '.' => return self.build_s(c, ".", TokenType::Dot),
             '&' => return self.build_s(c, "&", TokenType::Ampersand),
+            '|' => return self.build_s(c, "|", TokenType::Pipe),
+            '^' => return self.build_s(c, "^", TokenType::Caret),
+            '~' => return self.build_s(c, "~", TokenType::Tilde),
             _ => {}
         }
         self.consume();
@@ -264,74 +309,271 @@ impl Lexer {
         return self.build(c, TokenType::Invalid);
     }
 
-    fn lex_string(&mut self, c: &mut Compiler, tt: TokenType) -> Token {
+    fn lex_block_comment(&mut self, c: &mut Compiler) -> Token {
+        let mut depth: usize = 0;
+        loop {
+            if !self.has() {
+                c.errors.push(Error::fixed(
+                    "block comment is never closed",
+                    Source::new(self.path, self.start, self.start + 2)
+                ));
+                break;
+            }
+            match (self.current(), self.peek()) {
+                ('/', '*') => {
+                    self.consume();
+                    depth += 1;
+                }
+                ('*', '/') => {
+                    self.consume();
+                    depth -= 1;
+                }
+                _ => {}
+            }
+            self.consume();
+            if depth == 0 { break; }
+        }
+        return self.build(c, TokenType::Comment);
+    }
+
+    fn consume_digits(&mut self, c: &mut Compiler, radix: u32) {
+        let mut after_digit: bool = false;
+        let mut reported: bool = false;
+        while self.has() && (self.current().is_digit(radix) 
+                || self.current() == '_') {
+            if self.current() != '_' {
+                self.consume();
+                after_digit = true;
+                continue;
+            }
+            // separators may only be placed between two digits
+            if (!after_digit || !self.peek().is_digit(radix)) && !reported {
+                c.errors.push(Error::fixed(
+                    "digit separators must be placed between two digits",
+                    Source::new(self.path, self.pos, self.pos + 1)
+                ));
+                reported = true;
+            }
+            self.skip();
+            after_digit = false;
+        }
+    }
+
+    fn lex_number(&mut self, c: &mut Compiler) -> Token {
+        let radix: u32 = match (self.current(), self.peek()) {
+            ('0', 'x') => 16,
+            ('0', 'o') => 8,
+            ('0', 'b') => 2,
+            _ => 10
+        };
+        if radix != 10 {
+            self.skip();
+            self.skip();
+        }
+        self.consume_digits(c, radix);
+        let mut is_float: bool = false;
+        // fractions are also read in other bases to report them as errors
+        if self.current() == '.' && self.peek().is_ascii_digit() {
+            self.consume();
+            self.consume_digits(c, radix);
+            is_float = true;
+        }
+        if radix == 10 {
+            let exp_start: usize = self.pos;
+            if self.current() == 'e' || self.current() == 'E' {
+                self.consume();
+                if self.current() == '+' || self.current() == '-' {
+                    self.consume();
+                }
+                if self.current().is_ascii_digit() {
+                    self.consume_digits(c, 10);
+                } else {
+                    c.errors.push(Error::fixed(
+                        "the exponent of the float literal has no digits",
+                        Source::new(self.path, exp_start, self.pos)
+                    ));
+                }
+                is_float = true;
+            }
+        }
+        let digits: String = std::mem::take(&mut self.buffer);
+        let suffix_start: usize = self.pos;
+        while self.has() && (self.current().is_ascii_alphanumeric() 
+                || self.current() == '_') {
+            self.consume();
+        }
+        let suffix: String = std::mem::take(&mut self.buffer);
+        let suffix_source: Source = Source::new(self.path, suffix_start, self.pos);
+        let is_float_suffix: bool = suffix == "f32" || suffix == "f64";
+        match suffix.as_str() {
+            "" | "f32" | "f64" => {}
+            "u8" | "u16" | "u32" | "u64" | "usize" |
+            "s8" | "s16" | "s32" | "s64" if !is_float => {}
+            "u8" | "u16" | "u32" | "u64" | "usize" |
+            "s8" | "s16" | "s32" | "s64" => {
+                c.errors.push(Error::dynamic(
+                    format!(
+                        "the integer suffix '{}' cannot be used on a float literal",
+                        suffix
+                    ),
+                    suffix_source
+                ));
+            }
+            _ if suffix.starts_with(|c: char| c.is_ascii_digit()) => {
+                c.errors.push(Error::dynamic(
+                    format!(
+                        "'{}' is not a valid digit in a literal of base {}",
+                        &suffix[..1], radix
+                    ),
+                    suffix_source
+                ));
+            }
+            _ => {
+                c.errors.push(Error::dynamic(
+                    format!("'{}' is not a valid literal suffix", suffix),
+                    suffix_source
+                ));
+            }
+        }
+        if digits.is_empty() {
+            c.errors.push(Error::fixed(
+                "the literal has no digits",
+                Source::new(self.path, self.start, self.pos)
+            ));
+        }
+        if is_float || is_float_suffix {
+            if radix != 10 {
+                c.errors.push(Error::fixed(
+                    "float literals must be written in base 10",
+                    Source::new(self.path, self.start, self.pos)
+                ));
+            }
+            self.buffer = digits + &suffix;
+            return self.build(c, TokenType::Float);
+        }
+        // integers are stored in base 10 so that later stages don't need to
+        // handle prefixes and separators
+        match u128::from_str_radix(&digits, radix) {
+            Ok(value) => self.buffer = value.to_string(),
+            Err(_) => {
+                if !digits.is_empty() {
+                    c.errors.push(Error::fixed(
+                        "integer literal is too large",
+                        Source::new(self.path, self.start, self.pos)
+                    ));
+                }
+                self.buffer = String::from("0");
+            }
+        }
+        self.buffer.push_str(&suffix);
+        return self.build(c, TokenType::Integer);
+    }
+
+    fn lex_escape(&mut self, c: &mut Compiler) {
+        let seq_start: usize = self.pos;
         self.skip();
-        let mut escaped: bool = false;
-        while self.has() && (self.current() != '"' || escaped) {
-            if escaped {
-                let seq_start: usize = self.pos - 1;
-                match self.current() {
-                    '\n' => {
-                        self.skip();
-                    }
-                    '0' => {
-                        self.skip();
-                        self.buffer.push('\0');
-                    }
-                    'n' => {
-                        self.skip();
-                        self.buffer.push('\n');
-                    }
-                    'r' => {
-                        self.skip();
-                        self.buffer.push('\r');
-                    }
-                    't' => {
-                        self.skip();
-                        self.buffer.push('\t');
-                    }
-                    'u' if self.peek() == '{' => {
-                        self.skip();
-                        self.skip();
-                        let cp_start: usize = self.pos;
-                        while self.has() && self.current() != '}' {
-                            self.skip();
-                        }
-                        if self.current() != '}' {
-                            c.errors.push(Error::fixed(
-                                "unicode codepoint sequence is never closed",
-                                Source::new(self.path, seq_start, self.pos)
-                            ));
-                        }
-                        self.skip();
-                        let mut valid: bool = true;
-                        if let Ok(cp) = u32::from_str_radix(
-                            &self.source[cp_start..self.pos - 1], 
-                            16
-                        ) {
-                            if let Some(c) = char::from_u32(cp) {
-                                self.buffer.push(c);
-                            } else { valid = false; }
-                        } else { valid = false; }
-                        if !valid {
-                            c.errors.push(Error::fixed(
-                                "invalid unicode codepoint sequence",
-                                Source::new(
-                                    self.path, cp_start, self.pos - 1
-                                )
-                            ));
-                            self.buffer.push('�');
-                        }
+        match self.current() {
+            '\n' => {
+                self.skip();
+            }
+            '0' => {
+                self.skip();
+                self.buffer.push('\0');
+            }
+            'n' => {
+                self.skip();
+                self.buffer.push('\n');
+            }
+            'r' => {
+                self.skip();
+                self.buffer.push('\r');
+            }
+            't' => {
+                self.skip();
+                self.buffer.push('\t');
+            }
+            '\\' | '\'' | '"' => self.consume(),
+            'x' => {
+                self.skip();
+                let value_start: usize = self.pos;
+                let mut digit_count: usize = 0;
+                while digit_count < 2 && self.current().is_ascii_hexdigit() {
+                    self.skip();
+                    digit_count += 1;
+                }
+                let value: Option<char> = u8::from_str_radix(
+                    &self.source[value_start..self.pos], 16
+                ).ok()
+                    .filter(|v| digit_count == 2 && v.is_ascii())
+                    .map(|v| v as char);
+                match value {
+                    Some(value) => self.buffer.push(value),
+                    None => {
+                        c.errors.push(Error::fixed(
+                            concat!(
+                                "'\\x' must be followed by two hexadecimal ",
+                                "digits of at most 7F"
+                            ),
+                            Source::new(self.path, seq_start, self.pos)
+                        ));
+                        self.buffer.push('�');
                     }
-                    _ => self.consume()
                 }
-                escaped = false;
-            } else {
-                escaped = self.current() == '\\';
-                if !escaped {
-                    self.buffer.push(self.current());
+            }
+            'u' if self.peek() == '{' => {
+                self.skip();
+                self.skip();
+                let cp_start: usize = self.pos;
+                while self.has() && self.current() != '}' {
+                    self.skip();
+                }
+                if self.current() != '}' {
+                    c.errors.push(Error::fixed(
+                        "unicode codepoint sequence is never closed",
+                        Source::new(self.path, seq_start, self.pos)
+                    ));
                 }
                 self.skip();
+                let mut valid: bool = true;
+                if let Ok(cp) = u32::from_str_radix(
+                    &self.source[cp_start..self.pos - 1], 
+                    16
+                ) {
+                    if let Some(c) = char::from_u32(cp) {
+                        self.buffer.push(c);
+                    } else { valid = false; }
+                } else { valid = false; }
+                if !valid {
+                    c.errors.push(Error::fixed(
+                        "invalid unicode codepoint sequence",
+                        Source::new(
+                            self.path, cp_start, self.pos - 1
+                        )
+                    ));
+                    self.buffer.push('�');
+                }
+            }
+            _ => {
+                if self.has() { self.skip(); }
+                c.errors.push(Error::dynamic(
+                    format!(
+                        "'{}' is not a valid escape sequence",
+                        &self.source[seq_start..self.pos]
+                    ),
+                    Source::new(self.path, seq_start, self.pos)
+                ));
+                self.buffer.push('�');
+            }
+        }
+    }
+
+    fn lex_string(&mut self, c: &mut Compiler, tt: TokenType) -> Token {
+        self.skip();
+        while self.has() && self.current() != '"' {
+            if self.current() == '\\' {
+                self.lex_escape(c);
+            } else {
+                self.consume();
             }
         }
         if self.current() != '"' {
@@ -344,6 +586,37 @@ impl Lexer {
         return self.build(c, tt);
     }
 
+    fn lex_char(&mut self, c: &mut Compiler) -> Token {
+        self.skip();
+        while self.has() && self.current() != '\'' && self.current() != '\n' {
+            if self.current() == '\\' {
+                self.lex_escape(c);
+            } else {
+                self.consume();
+            }
+        }
+        if self.current() != '\'' {
+            c.errors.push(Error::fixed(
+                "character literal is never closed",
+                Source::new(self.path, self.start, self.start + 1)
+            ));
+            return self.build(c, TokenType::Invalid);
+        }
+        self.skip();
+        match self.buffer.chars().count() {
+            1 => {}
+            0 => c.errors.push(Error::fixed(
+                "character literal is empty",
+                Source::new(self.path, self.start, self.pos)
+            )),
+            _ => c.errors.push(Error::fixed(
+                "character literal contains more than one character",
+                Source::new(self.path, self.start, self.pos)
+            ))
+        }
+        return self.build(c, TokenType::Char);
+    }
+
     pub fn next(&mut self, c: &mut Compiler) -> Token {
         let mut current: Token = self.next_raw(c);
         while match current.t {
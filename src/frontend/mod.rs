@@ -8,6 +8,9 @@ pub use parser::*;
 mod ast;
 pub use ast::*;
 
+mod conditions;
+pub use conditions::*;
+
 mod symbols;
 pub use symbols::*;
 
@@ -15,4 +18,13 @@ mod path_exp;
 pub use path_exp::*;
 
 mod type_checking;
-pub use type_checking::*;
\ No newline at end of file
+pub use type_checking::*;
+
+mod layout;
+pub use layout::*;
+
+mod constants;
+pub use constants::*;
+
+mod operators;
+pub(crate) use operators::*;
\ No newline at end of file
@@ -0,0 +1,70 @@
+use crate::NodeType;
+
+pub(crate) const OPERATORS_PATH: &str = "<builtin>/std/ops.tola";
+
+pub(crate) const OPERATORS_SOURCE: &str = "\
+## Interfaces that allow structs to be used with operators.
+mod std::ops;
+
+## Overloads the operator '+'.
+pub interface Add { fun add(a *const Add, b Add): Add; }
+
+## Overloads the operator '-'.
+pub interface Subtract { fun subtract(a *const Subtract, b Subtract): Subtract; }
+
+## Overloads the operator '*'.
+pub interface Multiply { fun multiply(a *const Multiply, b Multiply): Multiply; }
+
+## Overloads the operator '/'.
+pub interface Divide { fun divide(a *const Divide, b Divide): Divide; }
+
+## Overloads the operator '%'.
+pub interface Remainder {
+    fun remainder(a *const Remainder, b Remainder): Remainder;
+}
+
+## Overloads the unary operator '-'.
+pub interface Negate { fun negate(a *const Negate): Negate; }
+
+## Overloads the operators '==' and '!='.
+pub interface Equal { fun equals(a *const Equal, b Equal): bool; }
+
+## Overloads the operators '<', '>', '<=' and '>='.
+## The result is negative, zero or positive if 'a' is less than,
+## equal to or greater than 'b'.
+pub interface Ordered { fun compare(a *const Ordered, b Ordered): s32; }
+";
+
+// (interface in 'std::ops', function, operator)
+pub(crate) fn operator_interface(
+    t: NodeType
+) -> Option<(&'static str, &'static str, &'static str)> {
+    return match t {
+        NodeType::Add => Some(("Add", "add", "+")),
+        NodeType::Subtract => Some(("Subtract", "subtract", "-")),
+        NodeType::Multiply => Some(("Multiply", "multiply", "*")),
+        NodeType::Divide => Some(("Divide", "divide", "/")),
+        NodeType::Remainder => Some(("Remainder", "remainder", "%")),
+        NodeType::Negate => Some(("Negate", "negate", "-")),
+        NodeType::Equal => Some(("Equal", "equals", "==")),
+        NodeType::NotEqual => Some(("Equal", "equals", "!=")),
+        NodeType::LessThan => Some(("Ordered", "compare", "<")),
+        NodeType::GreaterThan => Some(("Ordered", "compare", ">")),
+        NodeType::LessThanEqual => Some(("Ordered", "compare", "<=")),
+        NodeType::GreaterThanEqual => Some(("Ordered", "compare", ">=")),
+        _ => None
+    };
+}
+
+pub(crate) fn operator_symbol(t: NodeType) -> Option<&'static str> {
+    if let Some((_, _, symbol)) = operator_interface(t) { return Some(symbol); }
+    return match t {
+        NodeType::BitwiseAnd => Some("&"),
+        NodeType::BitwiseOr => Some("|"),
+        NodeType::BitwiseXor => Some("^"),
+        NodeType::AssignBitwiseAnd => Some("&="),
+        NodeType::AssignBitwiseOr => Some("|="),
+        NodeType::AssignBitwiseXor => Some("^="),
+        _ => None
+    };
+}
@@ -3,6 +3,7 @@ use crate::*;
 const PREC_NONE: usize = 0;
 const PREC_NEGATE: usize = 2;
 const PREC_NOT: usize = 2;
+const PREC_BITWISE_NOT: usize = 2;
 const PREC_ADDRESS_OF: usize = 2;
 const PREC_DEREF: usize = 2;
 const PREC_TYPE_CAST: usize = 3;
@@ -11,14 +12,23 @@ const PREC_DIVIDE: usize = 4;
 const PREC_REMAINDER: usize = 4;
 const PREC_ADD: usize = 5;
 const PREC_SUBTRACT: usize = 5;
-const PREC_LESS_THAN: usize = 6;
-const PREC_LESS_THAN_EQUAL: usize = 6;
-const PREC_GREATER_THAN: usize = 6;
-const PREC_GREATER_THAN_EQUAL: usize = 6;
-const PREC_EQUAL: usize = 7;
-const PREC_NOT_EQUAL: usize = 7;
-const PREC_AND: usize = 8;
-const PREC_OR: usize = 8;
+const PREC_SHIFT_LEFT: usize = 6;
+const PREC_SHIFT_RIGHT: usize = 6;
+const PREC_BITWISE_AND: usize = 7;
+const PREC_BITWISE_XOR: usize = 8;
+const PREC_BITWISE_OR: usize = 9;
+const PREC_LESS_THAN: usize = 10;
+const PREC_LESS_THAN_EQUAL: usize = 10;
+const PREC_GREATER_THAN: usize = 10;
+const PREC_GREATER_THAN_EQUAL: usize = 10;
+const PREC_EQUAL: usize = 11;
+const PREC_NOT_EQUAL: usize = 11;
+const PREC_AND: usize = 12;
+const PREC_OR: usize = 12;
+
+const ATTRIBUTES: &[&str] = &[
+    "inline", "cold", "packed", "align", "link_name", "deprecated"
+];
 
 fn get_infix_precedence(t: TokenType) -> usize {
     match t {
@@ -28,6 +38,11 @@ fn get_infix_precedence(t: TokenType) -> usize {
         TokenType::Percent => PREC_REMAINDER,
         TokenType::Plus => PREC_ADD,
         TokenType::Minus => PREC_SUBTRACT,
+        TokenType::DoubleLessThan => PREC_SHIFT_LEFT,
+        TokenType::DoubleGreaterThan => PREC_SHIFT_RIGHT,
+        TokenType::Ampersand => PREC_BITWISE_AND,
+        TokenType::Caret => PREC_BITWISE_XOR,
+        TokenType::Pipe => PREC_BITWISE_OR,
         TokenType::LessThan => PREC_LESS_THAN,
         TokenType::LessThanEqual => PREC_LESS_THAN_EQUAL,
         TokenType::GreaterThan => PREC_GREATER_THAN,
@@ -40,19 +55,54 @@ fn get_infix_precedence(t: TokenType) -> usize {
     }
 }
 
+fn is_statement(t: NodeType) -> bool {
+    return matches!(
+        t,
+        NodeType::Invalid |
+        NodeType::ModuleDecl | NodeType::UsageDecl | NodeType::When |
+        NodeType::StaticAssert |
+        NodeType::StructDecl | NodeType::EnumDecl | NodeType::InterfaceDecl |
+        NodeType::FunctionDecl | NodeType::VariableDecl | NodeType::TupleDecl |
+        NodeType::Return | NodeType::Continue | NodeType::Break |
+        NodeType::Defer | NodeType::Loop | NodeType::While | NodeType::For |
+        NodeType::Match |
+        NodeType::Assign | NodeType::AssignAdd | NodeType::AssignSubtract |
+        NodeType::AssignMultiply | NodeType::AssignDivide | 
+        NodeType::AssignRemainder | NodeType::AssignBitwiseAnd | 
+        NodeType::AssignBitwiseOr | NodeType::AssignBitwiseXor |
+        NodeType::AssignShiftLeft | NodeType::AssignShiftRight
+    );
+}
+
+
+// everything needed to go back after trying to parse something
+#[derive(Debug, Clone)]
+struct ParserState {
+    lexer: Lexer,
+    last: Option<Token>,
+    current: Token,
+    doc: Option<AstNode>,
+    error_count: usize
+}
 
 #[derive(Debug)]
 pub struct Parser<'c> {
     comp: &'c mut Compiler,
     lexer: Lexer,
     last: Option<Token>,
-    current: Token
+    current: Token,
+    doc: Option<AstNode>,
+    struct_literals: bool
 }
 
 impl<'c> Parser<'c> {
     pub fn new(comp: &'c mut Compiler, mut lexer: Lexer) -> Parser<'c> {
         let current: Token = lexer.next(comp);
-        return Parser { comp, lexer, last: None, current };
+        let mut parser: Parser = Parser { 
+            comp, lexer, last: None, current, doc: None, struct_literals: true 
+        };
+        parser.read_doc();
+        return parser;
     }
 
     fn construct_new(
@@ -78,6 +128,43 @@ impl<'c> Parser<'c> {
     fn next(&mut self) {
         self.last = Some(self.current);
         self.current = self.lexer.next(self.comp);
+        self.read_doc();
+    }
+
+    fn read_doc(&mut self) {
+        if let Some(doc) = self.doc.take() { self.report_doc(&doc); }
+        if self.current.t != TokenType::DocComment { return; }
+        let start: Source = self.current.source;
+        let mut end: Source = self.current.source;
+        let mut lines: Vec<String> = Vec::new();
+        while self.current.t == TokenType::DocComment {
+            lines.push(String::from(self.comp.strings.get(self.current.content)));
+            end = self.current.source;
+            self.current = self.lexer.next(self.comp);
+        }
+        let doc: StringIdx = self.comp.strings.insert(&lines.join("\n"));
+        self.doc = Some(self.construct_new(
+            NodeType::DocComment, Source::across(start, end),
+            NodeValue::String(doc), Vec::new()
+        ));
+    }
+
+    fn attach_doc(&mut self, doc: Option<AstNode>, n: &mut AstNode) {
+        let Some(doc) = doc else { return; };
+        match n.t {
+            NodeType::ModuleDecl | NodeType::StructDecl | NodeType::EnumDecl |
+            NodeType::InterfaceDecl | NodeType::FunctionDecl |
+            NodeType::VariableDecl => n.children.insert(0, doc),
+            NodeType::Invalid => {}
+            _ => self.report_doc(&doc)
+        }
+    }
+
+    fn report_doc(&mut self, doc: &AstNode) {
+        self.comp.errors.push(Error::fixed(
+            "documentation comments must be placed before a declaration",
+            doc.source
+        ));
     }
 
     fn report_unexpected(&mut self) -> AstNode {
@@ -138,14 +225,31 @@ impl<'c> Parser<'c> {
                 TokenType::EndOfFile => break,
                 _ => {}
             };
-            let s: AstNode = self.parse_statement(global).unwrap_or_else(|n| n);
+            let doc: Option<AstNode> = self.doc.take();
+            let mut s: AstNode = self.parse_attributed_statement(global)
+                .unwrap_or_else(|n| n);
+            self.attach_doc(doc, &mut s);
+            // an expression at the end of a block is the value of the block
+            if !global && self.current.t == TokenType::BraceClose 
+                    && !is_statement(s.t) {
+                nodes.push(self.construct_new(
+                    NodeType::BlockValue, s.source, NodeValue::None, vec!(s)
+                ));
+                continue;
+            }
             match s.t {
-                NodeType::Invalid |
+                NodeType::Invalid | NodeType::When |
                 NodeType::EnumDecl | NodeType::InterfaceDecl |
-                NodeType::If | NodeType::Loop | NodeType::While => {}
+                NodeType::If | NodeType::Loop | NodeType::While |
+                NodeType::For | NodeType::Match | NodeType::Block => {}
                 NodeType::FunctionDecl if s.children
                     .iter().find(|c| c.t == NodeType::Block)
                     .is_some() => {}
+                NodeType::Defer if matches!(
+                    s.children[0].t,
+                    NodeType::Block | NodeType::If | NodeType::Loop |
+                    NodeType::While | NodeType::For | NodeType::Match
+                ) => {}
                 _ => {
                     if self.current.t != TokenType::Semicolon {
                         self.comp.errors.push(Error::fixed(
@@ -157,9 +261,162 @@ impl<'c> Parser<'c> {
             }
             nodes.push(s);
         }
+        if let Some(doc) = self.doc.take() { self.report_doc(&doc); }
         return nodes;
     }
 
+    fn parse_attributes(&mut self) -> Result<Vec<AstNode>, AstNode> {
+        let mut attributes: Vec<AstNode> = Vec::new();
+        while self.current.t == TokenType::At {
+            let start: Source = self.current.source;
+            self.next();
+            self.expect(&[TokenType::Identifier])?;
+            let name: StringIdx = self.current.content;
+            self.next();
+            let mut args: Vec<AstNode> = Vec::new();
+            if self.current.t == TokenType::ParenOpen {
+                self.next();
+                while self.current.t != TokenType::ParenClose {
+                    args.push(self.parse_full_expression()?);
+                    self.expect(&[TokenType::Comma, TokenType::ParenClose])?;
+                    if self.current.t == TokenType::Comma { self.next(); }
+                }
+                self.next();
+            }
+            attributes.push(self.construct_new(
+                NodeType::Attribute,
+                Source::across(
+                    start, self.last.expect("cannot be first").source
+                ),
+                NodeValue::String(name),
+                args
+            ));
+        }
+        return Ok(attributes);
+    }
+
+    fn check_attributes(
+        &mut self, attributes: &[AstNode], allowed: &[&str], described: &str
+    ) {
+        let mut seen: Vec<StringIdx> = Vec::new();
+        for attribute in attributes {
+            let NodeValue::String(name) = attribute.value
+                else { unreachable!("should have a value") };
+            let name_s: String = String::from(self.comp.strings.get(name));
+            if seen.contains(&name) {
+                self.comp.errors.push(Error::dynamic(
+                    format!(
+                        "the attribute '@{}' is specified more than once",
+                        name_s
+                    ),
+                    attribute.source
+                ));
+                continue;
+            }
+            seen.push(name);
+            if !ATTRIBUTES.contains(&name_s.as_str()) {
+                self.comp.errors.push(Error::dynamic(
+                    format!("'@{}' is not a known attribute", name_s),
+                    attribute.source
+                ));
+                continue;
+            }
+            if !allowed.contains(&name_s.as_str()) {
+                self.comp.errors.push(Error::dynamic(
+                    format!(
+                        "the attribute '@{}' cannot be applied to {}",
+                        name_s, described
+                    ),
+                    attribute.source
+                ));
+                continue;
+            }
+            let is_valid: bool = match (name_s.as_str(), &attribute.children[..]) {
+                ("align", [a]) => match a.value {
+                    NodeValue::String(v) if a.t == NodeType::IntegerLiteral =>
+                        self.comp.strings.get(v).parse::<u64>()
+                            .map(|v| v.is_power_of_two())
+                            .unwrap_or(false),
+                    _ => false
+                },
+                ("link_name", [s]) => s.t == NodeType::StringLiteral,
+                ("deprecated", [s]) => s.t == NodeType::StringLiteral,
+                ("inline" | "cold" | "packed" | "deprecated", []) => true,
+                _ => false
+            };
+            if is_valid { continue; }
+            let expected: &str = match name_s.as_str() {
+                "align" => "an integer literal that is a power of two",
+                "link_name" => "a string literal",
+                "deprecated" => "no arguments or a string literal",
+                _ => "no arguments"
+            };
+            self.comp.errors.push(Error::dynamic(
+                format!("the attribute '@{}' expects {}", name_s, expected),
+                attribute.source
+            ));
+        }
+    }
+
+    fn check_arg_attributes(
+        &mut self, args: &AstNode, allowed: &[&str], described: &str
+    ) {
+        for arg in &args.children {
+            let attributes: Vec<AstNode> = arg.children.iter()
+                .filter(|c| c.t == NodeType::Attribute)
+                .cloned()
+                .collect();
+            self.check_attributes(&attributes, allowed, described);
+        }
+    }
+
+    fn parse_attributed_statement(
+        &mut self, global: bool
+    ) -> Result<AstNode, AstNode> {
+        let attributes: Vec<AstNode> = self.parse_attributes()?;
+        // documentation may also be placed between attributes and declarations
+        let doc: Option<AstNode> = self.doc.take();
+        let mut s: AstNode = self.parse_statement(global)?;
+        self.attach_doc(doc, &mut s);
+        if attributes.is_empty() { return Ok(s); }
+        let (allowed, described): (&[&str], &str) = match s.t {
+            NodeType::Invalid => return Ok(s),
+            NodeType::FunctionDecl => 
+                (&["inline", "cold", "link_name", "deprecated"], "functions"),
+            NodeType::StructDecl => 
+                (&["packed", "align", "deprecated"], "structs"),
+            NodeType::VariableDecl if global => 
+                (&["align", "link_name", "deprecated"], "global variables"),
+            NodeType::VariableDecl => (&["align"], "local variables"),
+            NodeType::ModuleDecl => (&[], "modules"),
+            NodeType::UsageDecl => (&[], "usages"),
+            NodeType::EnumDecl => (&[], "enums"),
+            NodeType::InterfaceDecl => (&[], "interfaces"),
+            _ => (&[], "statements")
+        };
+        self.check_attributes(&attributes, allowed, described);
+        if allowed.is_empty() { return Ok(s); }
+        let is_linked: bool = !allowed.contains(&"link_name")
+            || s.children.iter().any(|c|
+                c.t == NodeType::IsExternal || c.t == NodeType::IsExported
+            );
+        let link_name: StringIdx = self.comp.strings.insert("link_name");
+        let link_name: Option<Source> = attributes.iter()
+            .find(|a| a.value == NodeValue::String(link_name))
+            .map(|a| a.source);
+        if let (Some(link_name), false) = (link_name, is_linked) {
+            self.comp.errors.push(Error::fixed(
+                concat!(
+                    "the attribute '@link_name' can only be applied to ",
+                    "external or exported declarations"
+                ),
+                link_name
+            ));
+        }
+        s.children.splice(0..0, attributes);
+        return Ok(s);
+    }
+
     fn parse_path(&mut self) -> Result<PathIdx, AstNode> {
         let mut p: Vec<StringIdx> = Vec::new();
         self.expect(&[TokenType::Identifier])?;
@@ -187,12 +444,38 @@ impl<'c> Parser<'c> {
         let mut args: Vec<AstNode> = Vec::new();
         while self.current.t != TokenType::BracketClose {
             self.expect(&[TokenType::Identifier])?;
+            let arg_start: Source = self.current.source;
+            let name: StringIdx = self.current.content;
+            self.next();
+            // constraints name interfaces or categories like 'integer'
+            let mut constraints: Vec<AstNode> = Vec::new();
+            if self.current.t == TokenType::Colon {
+                self.next();
+                loop {
+                    let constraint_start: Source = self.current.source;
+                    let constraint: PathIdx = self.parse_path()?;
+                    let t_args: AstNode = self.parse_t_args()?;
+                    constraints.push(self.construct_new(
+                        NodeType::PathAccess,
+                        Source::across(
+                            constraint_start,
+                            self.last.expect("cannot be first").source
+                        ),
+                        NodeValue::Path(constraint),
+                        vec!(t_args)
+                    ));
+                    if self.current.t != TokenType::Plus { break; }
+                    self.next();
+                }
+            }
             args.push(self.construct_new(
-                NodeType::ArgumentDecl, self.current.source,
-                NodeValue::String(self.current.content),
-                Vec::new()
+                NodeType::ArgumentDecl,
+                Source::across(
+                    arg_start, self.last.expect("cannot be first").source
+                ),
+                NodeValue::String(name),
+                constraints
             ));
-            self.next();
             self.expect(&[TokenType::Comma, TokenType::BracketClose])?;
             if self.current.t == TokenType::Comma { self.next(); }
         }
@@ -204,13 +487,33 @@ impl<'c> Parser<'c> {
         ));
     }
 
-    fn parse_args_def(&mut self) -> Result<AstNode, AstNode> {
+    fn parse_args_def(&mut self, is_external: bool) -> Result<AstNode, AstNode> {
         self.expect(&[TokenType::ParenOpen])?;
         let start: Source = self.current.source;
         self.next();
         let mut args: Vec<AstNode> = Vec::new();
         while self.current.t != TokenType::ParenClose {
+            if self.current.t == TokenType::TripleDot {
+                if is_external {
+                    args.push(self.construct_empty(
+                        NodeType::IsVariadic, self.current.source
+                    ));
+                } else {
+                    self.comp.errors.push(Error::fixed(
+                        concat!(
+                            "only external functions can take a variable ",
+                            "number of arguments"
+                        ),
+                        self.current.source
+                    ));
+                }
+                self.next();
+                self.expect(&[TokenType::ParenClose])?;
+                break;
+            }
             let mut arg_children: Vec<AstNode> = Vec::new();
+            arg_children.extend(self.doc.take());
+            arg_children.extend(self.parse_attributes()?);
             let arg_start: Source = self.current.source;
             if self.current.t == TokenType::KeywordConst {
                 arg_children.push(self.construct_empty(
@@ -285,6 +588,50 @@ impl<'c> Parser<'c> {
         ));
     }
 
+    fn save_state(&self) -> ParserState {
+        return ParserState {
+            lexer: self.lexer.clone(),
+            last: self.last,
+            current: self.current,
+            doc: self.doc.clone(),
+            error_count: self.comp.errors.len()
+        };
+    }
+
+    fn restore_state(&mut self, state: ParserState) {
+        self.lexer = state.lexer;
+        self.last = state.last;
+        self.current = state.current;
+        self.doc = state.doc;
+        self.comp.errors.truncate(state.error_count);
+    }
+
+    fn parse_expr_t_args(
+        &mut self
+    ) -> Result<(AstNode, Option<AstNode>), AstNode> {
+        // 'a[i]' might also be an index into 'a', which can only be decided
+        // once it is known whether 'a' is a variable, so both are kept
+        let start: ParserState = self.save_state();
+        let Ok(t_args) = self.parse_t_args() else {
+            // the index is parsed as an infix operator instead
+            self.restore_state(start);
+            return Ok((self.construct_new(
+                NodeType::ArgumentList, self.current.source,
+                NodeValue::None, Vec::new()
+            ), None));
+        };
+        if t_args.children.len() != 1 { return Ok((t_args, None)); }
+        let after_t_args: ParserState = self.save_state();
+        self.restore_state(start);
+        self.next();
+        let index: Option<AstNode> = self.parse_full_expression().ok()
+            .filter(|_| self.current.t == TokenType::BracketClose
+                && after_t_args.last.map(|l| l.source) == Some(self.current.source)
+            );
+        self.restore_state(after_t_args);
+        return Ok((t_args, index));
+    }
+
     fn parse_used_paths(&mut self) -> Result<Vec<Vec<StringIdx>>, AstNode> {
         let mut paths: Vec<Vec<StringIdx>> = Vec::new();
         loop {
@@ -356,13 +703,15 @@ impl<'c> Parser<'c> {
                 TokenType::KeywordMod, TokenType::KeywordUse,
                 TokenType::KeywordStruct, TokenType::KeywordEnum,
                 TokenType::KeywordInterface, TokenType::KeywordFun,
-                TokenType::KeywordVar, TokenType::KeywordConst
+                TokenType::KeywordVar, TokenType::KeywordConst,
+                TokenType::KeywordWhen, TokenType::KeywordStaticAssert
             ])?;
         } else {
             self.expect_not(&[
                 TokenType::KeywordMod, TokenType::KeywordUse,
                 TokenType::KeywordStruct, TokenType::KeywordEnum,
-                TokenType::KeywordInterface, TokenType::KeywordFun
+                TokenType::KeywordInterface, TokenType::KeywordFun,
+                TokenType::KeywordWhen
             ])?;
         }
         match self.current.t {
@@ -379,6 +728,33 @@ impl<'c> Parser<'c> {
                     Vec::new()
                 ));
             }
+            TokenType::KeywordWhen => return self.parse_when(),
+            TokenType::KeywordStaticAssert => {
+                self.next();
+                self.expect(&[TokenType::ParenOpen])?;
+                self.next();
+                let mut children: Vec<AstNode> = vec!(
+                    self.parse_full_expression()?
+                );
+                if self.current.t == TokenType::Comma {
+                    self.next();
+                    self.expect(&[TokenType::String])?;
+                    children.push(self.construct_new(
+                        NodeType::StringLiteral, self.current.source,
+                        NodeValue::String(self.current.content), Vec::new()
+                    ));
+                    self.next();
+                }
+                self.expect(&[TokenType::ParenClose])?;
+                let end: Source = self.current.source;
+                self.next();
+                return Ok(self.construct_new(
+                    NodeType::StaticAssert,
+                    Source::across(start, end),
+                    NodeValue::None,
+                    children
+                ));
+            }
             TokenType::KeywordUse => {
                 self.next();
                 let p_source: Source = self.current.source;
@@ -415,14 +791,36 @@ impl<'c> Parser<'c> {
                 let name: StringIdx = self.current.content;
                 self.next();
                 children.push(self.parse_t_args_def()?);
-                let args: AstNode = self.parse_args_def()?;
-                let end: Source = args.source;
+                let args: AstNode = self.parse_args_def(false)?;
+                self.check_arg_attributes(
+                    &args, &["align", "deprecated"], "fields"
+                );
+                let mut end: Source = args.source;
                 children.push(args);
                 if self.current.t == TokenType::Colon {
+                    let list_start: Source = self.current.source;
                     self.next();
-                    todo!(
-                        "interface parsing + make 'end' mut and update it"
-                    );
+                    let mut interfaces: Vec<AstNode> = Vec::new();
+                    loop {
+                        let interface_start: Source = self.current.source;
+                        let interface: PathIdx = self.parse_path()?;
+                        let t_args: AstNode = self.parse_t_args()?;
+                        end = self.last.expect("cannot be first").source;
+                        interfaces.push(self.construct_new(
+                            NodeType::PathAccess,
+                            Source::across(interface_start, end),
+                            NodeValue::Path(interface),
+                            vec!(t_args)
+                        ));
+                        if self.current.t != TokenType::Comma { break; }
+                        self.next();
+                    }
+                    children.push(self.construct_new(
+                        NodeType::InterfaceList,
+                        Source::across(list_start, end),
+                        NodeValue::None,
+                        interfaces
+                    ));
                 }
                 return Ok(self.construct_new(
                     NodeType::StructDecl,
@@ -432,10 +830,128 @@ impl<'c> Parser<'c> {
                 ));
             }
             TokenType::KeywordEnum => {
-                todo!()
+                let mut children: Vec<AstNode> = Vec::new();
+                if is_public {
+                    children.push(self.construct_empty(
+                        NodeType::IsPublic, self.current.source
+                    ));
+                }
+                self.next();
+                self.expect(&[TokenType::Identifier])?;
+                let name: StringIdx = self.current.content;
+                self.next();
+                if self.current.t == TokenType::BraceOpen {
+                    children.push(self.construct_empty(
+                        NodeType::U32Type, self.current.source
+                    ));
+                } else {
+                    children.push(self.parse_type()?);
+                }
+                self.expect(&[TokenType::BraceOpen])?;
+                self.next();
+                while self.current.t != TokenType::BraceClose {
+                    self.expect(&[TokenType::Identifier])?;
+                    let variant_start: Source = self.current.source;
+                    let variant_name: StringIdx = self.current.content;
+                    let mut variant_children: Vec<AstNode> = Vec::new();
+                    variant_children.extend(self.doc.take());
+                    self.next();
+                    if self.current.t == TokenType::ParenOpen {
+                        let payload_start: Source = self.current.source;
+                        self.next();
+                        let mut payload: Vec<AstNode> = Vec::new();
+                        while self.current.t != TokenType::ParenClose {
+                            payload.push(self.parse_type()?);
+                            self.expect(&[
+                                TokenType::Comma, TokenType::ParenClose
+                            ])?;
+                            if self.current.t == TokenType::Comma { self.next(); }
+                        }
+                        variant_children.push(self.construct_new(
+                            NodeType::VariantPayload,
+                            Source::across(payload_start, self.current.source),
+                            NodeValue::None, payload
+                        ));
+                        self.next();
+                    }
+                    if self.current.t == TokenType::Equal {
+                        self.next();
+                        variant_children.push(self.parse_full_expression()?);
+                    }
+                    children.push(self.construct_new(
+                        NodeType::EnumVariant,
+                        Source::across(
+                            variant_start, 
+                            self.last.expect("cannot be first").source
+                        ),
+                        NodeValue::String(variant_name),
+                        variant_children
+                    ));
+                    self.expect(&[TokenType::Comma, TokenType::BraceClose])?;
+                    if self.current.t == TokenType::Comma { self.next(); }
+                }
+                let end: Source = self.current.source;
+                self.next();
+                return Ok(self.construct_new(
+                    NodeType::EnumDecl,
+                    Source::across(start, end),
+                    NodeValue::String(name),
+                    children
+                ));
             }
             TokenType::KeywordInterface => {
-                todo!()
+                let mut children: Vec<AstNode> = Vec::new();
+                if is_public {
+                    children.push(self.construct_empty(
+                        NodeType::IsPublic, self.current.source
+                    ));
+                }
+                self.next();
+                self.expect(&[TokenType::Identifier])?;
+                let name: StringIdx = self.current.content;
+                self.next();
+                self.expect(&[TokenType::BraceOpen])?;
+                self.next();
+                while self.current.t != TokenType::BraceClose {
+                    self.expect(&[TokenType::KeywordFun])?;
+                    let fun_start: Source = self.current.source;
+                    let mut fun_children: Vec<AstNode> = Vec::new();
+                    fun_children.extend(self.doc.take());
+                    self.next();
+                    self.expect(&[TokenType::Identifier])?;
+                    let fun_name: StringIdx = self.current.content;
+                    self.next();
+                    fun_children.push(self.parse_t_args_def()?);
+                    let args: AstNode = self.parse_args_def(false)?;
+                    self.check_arg_attributes(&args, &[], "parameters");
+                    fun_children.push(args);
+                    if self.current.t == TokenType::Colon {
+                        self.next();
+                        fun_children.push(self.parse_type()?);
+                    } else {
+                        fun_children.push(self.construct_empty(
+                            NodeType::UnitType, self.current.source
+                        ));
+                    }
+                    let fun_end: Source = self.last
+                        .expect("cannot be first").source;
+                    self.expect(&[TokenType::Semicolon])?;
+                    self.next();
+                    children.push(self.construct_new(
+                        NodeType::FunctionDecl,
+                        Source::across(fun_start, fun_end),
+                        NodeValue::String(fun_name),
+                        fun_children
+                    ));
+                }
+                let end: Source = self.current.source;
+                self.next();
+                return Ok(self.construct_new(
+                    NodeType::InterfaceDecl,
+                    Source::across(start, end),
+                    NodeValue::String(name),
+                    children
+                ));
             }
             TokenType::KeywordFun => {
                 let mut children: Vec<AstNode> = Vec::new();
@@ -455,9 +971,7 @@ impl<'c> Parser<'c> {
                     ));
                 }
                 self.next();
-                self.expect(&[TokenType::Identifier])?;
-                let name: StringIdx = self.current.content;
-                self.next();
+                let name: PathIdx = self.parse_path()?;
                 let t_args: AstNode = self.parse_t_args_def()?;
                 if is_exported && t_args.children.len() > 0 {
                     self.comp.errors.push(Error::dynamic(
@@ -466,13 +980,22 @@ impl<'c> Parser<'c> {
                                 "'{}' is exported and may therefore not ",
                                 "specify any template arguments"
                             ),
-                            self.comp.strings.get(name)
+                            name.display(self.comp)
                         ), 
                         t_args.source
                     ))
                 }
                 children.push(t_args);
-                children.push(self.parse_args_def()?);
+                let mut args: AstNode = self.parse_args_def(is_external)?;
+                self.check_arg_attributes(&args, &[], "parameters");
+                // variadic functions are marked like other properties,
+                // before their template arguments
+                if args.children.last().map(|a| a.t) == Some(NodeType::IsVariadic) {
+                    let variadic: AstNode = args.children.pop()
+                        .expect("was checked");
+                    children.insert(children.len() - 1, variadic);
+                }
+                children.push(args);
                 if self.current.t == TokenType::Colon {
                     self.next();
                     children.push(self.parse_type()?);
@@ -484,12 +1007,16 @@ impl<'c> Parser<'c> {
                 if !is_external {
                     children.push(self.parse_block()?);
                 }
+                let value: NodeValue = match self.comp.paths.get(name) {
+                    [name] => NodeValue::String(*name),
+                    _ => NodeValue::Path(name)
+                };
                 return Ok(self.construct_new(
                     NodeType::FunctionDecl,
                     Source::across(
                         start, self.last.expect("cannot be first").source
                     ),
-                    NodeValue::String(name),
+                    value,
                     children
                 ));
             }
@@ -511,6 +1038,9 @@ impl<'c> Parser<'c> {
                     ));
                 }
                 self.next();
+                if self.current.t == TokenType::ParenOpen && !global {
+                    return self.parse_tuple_decl(start, children);
+                }
                 self.expect(&[TokenType::Identifier])?;
                 let name: StringIdx = self.current.content;
                 self.next();
@@ -550,6 +1080,13 @@ impl<'c> Parser<'c> {
                     ));
                 }
                 self.next();
+                if self.current.t == TokenType::ParenOpen && !global {
+                    children.push(self.construct_new(
+                        NodeType::IsConstant, start,
+                        NodeValue::None, Vec::new()
+                    ));
+                    return self.parse_tuple_decl(start, children);
+                }
                 self.expect(&[TokenType::Identifier])?;
                 let name: StringIdx = self.current.content;
                 self.next();
@@ -601,38 +1138,7 @@ impl<'c> Parser<'c> {
                     NodeValue::None, Vec::new()
                 ));
             }
-            TokenType::KeywordIf => {
-                self.next();
-                let mut children: Vec<AstNode> = Vec::new();
-                children.push(self.parse_full_expression()?);
-                children.push(self.parse_block()?);
-                if self.current.t == TokenType::KeywordElse {
-                    self.next();
-                    self.expect(&[TokenType::KeywordIf, TokenType::BraceOpen])?;
-                    if self.current.t == TokenType::BraceOpen {
-                        children.push(self.parse_block()?);
-                    } else {
-                        let else_st: AstNode = self.parse_statement(false)?;
-                        children.push(self.construct_new(
-                            NodeType::Block, else_st.source,
-                            NodeValue::None, vec!(else_st)
-                        ));
-                    }
-                } else {
-                    children.push(self.construct_empty(
-                        NodeType::Block, 
-                        self.last.expect("cannot be first").source
-                    ));
-                }
-                return Ok(self.construct_new(
-                    NodeType::If, 
-                    Source::across(
-                        start, self.last.expect("cannot be first").source
-                    ), 
-                    NodeValue::None, 
-                    children
-                ));
-            }
+            TokenType::KeywordIf => return self.parse_if(),
             TokenType::KeywordLoop => {
                 self.next();
                 let body: AstNode = self.parse_block()?;
@@ -643,20 +1149,102 @@ impl<'c> Parser<'c> {
             }
             TokenType::KeywordWhile => {
                 self.next();
-                let condition: AstNode = self.parse_full_expression()?;
+                let condition: AstNode = self.parse_condition()?;
                 let body: AstNode = self.parse_block()?;
                 return Ok(self.construct_new(
                     NodeType::While, Source::across(start, body.source),
                     NodeValue::None, vec!(condition, body)
                 ));
             }
+            TokenType::KeywordFor => {
+                self.next();
+                self.expect(&[TokenType::Identifier])?;
+                let name: StringIdx = self.current.content;
+                self.next();
+                self.expect(&[TokenType::KeywordIn])?;
+                self.next();
+                let mut iterated: AstNode = self.parse_condition()?;
+                if self.current.t == TokenType::DoubleDot 
+                        || self.current.t == TokenType::DoubleDotEqual {
+                    let nt: NodeType = if self.current.t == TokenType::DoubleDot {
+                        NodeType::Range
+                    } else { NodeType::InclusiveRange };
+                    self.next();
+                    let mut bounds: Vec<AstNode> = vec!(iterated);
+                    bounds.push(self.parse_condition()?);
+                    let is_step: bool = self.current.t == TokenType::Identifier
+                        && self.comp.strings.get(self.current.content) == "step";
+                    if is_step {
+                        self.next();
+                        bounds.push(self.parse_condition()?);
+                    }
+                    iterated = self.construct_new(
+                        nt,
+                        Source::across(
+                            bounds[0].source, 
+                            bounds.last().expect("has bounds").source
+                        ),
+                        NodeValue::None, bounds
+                    );
+                }
+                let body: AstNode = self.parse_block()?;
+                return Ok(self.construct_new(
+                    NodeType::For, Source::across(start, body.source),
+                    NodeValue::String(name), vec!(iterated, body)
+                ));
+            }
+            TokenType::KeywordDefer => {
+                self.next();
+                let deferred: AstNode = if self.current.t == TokenType::BraceOpen {
+                    self.parse_block()?
+                } else {
+                    self.parse_statement(false)?
+                };
+                return Ok(self.construct_new(
+                    NodeType::Defer, Source::across(start, deferred.source),
+                    NodeValue::None, vec!(deferred)
+                ));
+            }
+            TokenType::KeywordMatch => {
+                self.next();
+                let value: AstNode = self.parse_condition()?;
+                self.expect(&[TokenType::BraceOpen])?;
+                self.next();
+                let mut children: Vec<AstNode> = vec!(value);
+                while self.current.t != TokenType::BraceClose {
+                    let mut patterns: Vec<AstNode> = vec!(self.parse_pattern()?);
+                    while self.current.t == TokenType::Comma {
+                        self.next();
+                        patterns.push(self.parse_pattern()?);
+                    }
+                    self.expect(&[TokenType::EqualGreaterThan])?;
+                    self.next();
+                    let body: AstNode = self.parse_block()?;
+                    let arm_source: Source = Source::across(
+                        patterns[0].source, body.source
+                    );
+                    patterns.push(body);
+                    children.push(self.construct_new(
+                        NodeType::MatchArm, arm_source, NodeValue::None, patterns
+                    ));
+                }
+                let end: Source = self.current.source;
+                self.next();
+                return Ok(self.construct_new(
+                    NodeType::Match, Source::across(start, end),
+                    NodeValue::None, children
+                ));
+            }
             _ => {
                 let expr: AstNode = self.parse_full_expression()?;
                 match self.current.t {
                     TokenType::Equal |
                     TokenType::PlusEqual | TokenType::MinusEqual |
                     TokenType::AsteriskEqual | TokenType::SlashEqual |
-                    TokenType::PercentEqual => {
+                    TokenType::PercentEqual |
+                    TokenType::AmpersandEqual | TokenType::PipeEqual |
+                    TokenType::CaretEqual | TokenType::DoubleLessThanEqual |
+                    TokenType::DoubleGreaterThanEqual => {
                         let nt: NodeType = match self.current.t {
                             TokenType::Equal => NodeType::Assign,
                             TokenType::PlusEqual => NodeType::AssignAdd,
@@ -664,6 +1252,11 @@ impl<'c> Parser<'c> {
                             TokenType::AsteriskEqual => NodeType::AssignMultiply,
                             TokenType::SlashEqual => NodeType::AssignDivide,
                             TokenType::PercentEqual => NodeType::AssignRemainder,
+                            TokenType::AmpersandEqual => NodeType::AssignBitwiseAnd,
+                            TokenType::PipeEqual => NodeType::AssignBitwiseOr,
+                            TokenType::CaretEqual => NodeType::AssignBitwiseXor,
+                            TokenType::DoubleLessThanEqual => NodeType::AssignShiftLeft,
+                            TokenType::DoubleGreaterThanEqual => NodeType::AssignShiftRight,
                             _ => unreachable!()
                         };
                         self.next();
@@ -680,7 +1273,230 @@ impl<'c> Parser<'c> {
     }
 
     fn parse_full_expression(&mut self) -> Result<AstNode, AstNode> {
-        return self.parse_expression(usize::MAX);
+        let struct_literals: bool = self.struct_literals;
+        self.struct_literals = true;
+        let expr: Result<AstNode, AstNode> = self.parse_expression(usize::MAX);
+        self.struct_literals = struct_literals;
+        return expr;
+    }
+
+    fn parse_tuple_decl(
+        &mut self, start: Source, mut children: Vec<AstNode>
+    ) -> Result<AstNode, AstNode> {
+        self.expect(&[TokenType::ParenOpen])?;
+        self.next();
+        while self.current.t != TokenType::ParenClose {
+            self.expect(&[TokenType::Identifier])?;
+            let name: StringIdx = self.current.content;
+            children.push(if self.comp.strings.get(name) == "_" {
+                self.construct_empty(NodeType::Wildcard, self.current.source)
+            } else {
+                self.construct_new(
+                    NodeType::Binding, self.current.source,
+                    NodeValue::String(name), Vec::new()
+                )
+            });
+            self.next();
+            self.expect(&[TokenType::Comma, TokenType::ParenClose])?;
+            if self.current.t == TokenType::Comma { self.next(); }
+        }
+        self.next();
+        self.expect(&[TokenType::Equal])?;
+        self.next();
+        let value: AstNode = self.parse_full_expression()?;
+        let end: Source = value.source;
+        children.push(value);
+        return Ok(self.construct_new(
+            NodeType::TupleDecl, Source::across(start, end),
+            NodeValue::None, children
+        ));
+    }
+
+    fn parse_if(&mut self) -> Result<AstNode, AstNode> {
+        let start: Source = self.current.source;
+        self.expect(&[TokenType::KeywordIf])?;
+        self.next();
+        let mut children: Vec<AstNode> = Vec::new();
+        let condition: AstNode = self.parse_condition()?;
+        if self.current.t == TokenType::KeywordIs {
+            self.next();
+            let pattern: AstNode = self.parse_pattern()?;
+            children.push(self.construct_new(
+                NodeType::Is, 
+                Source::across(condition.source, pattern.source),
+                NodeValue::None, vec!(condition, pattern)
+            ));
+        } else {
+            children.push(condition);
+        }
+        children.push(self.parse_block()?);
+        if self.current.t == TokenType::KeywordElse {
+            self.next();
+            self.expect(&[TokenType::KeywordIf, TokenType::BraceOpen])?;
+            if self.current.t == TokenType::BraceOpen {
+                children.push(self.parse_block()?);
+            } else {
+                let else_if: AstNode = self.parse_if()?;
+                let value: AstNode = self.construct_new(
+                    NodeType::BlockValue, else_if.source,
+                    NodeValue::None, vec!(else_if)
+                );
+                children.push(self.construct_new(
+                    NodeType::Block, value.source,
+                    NodeValue::None, vec!(value)
+                ));
+            }
+        }
+        return Ok(self.construct_new(
+            NodeType::If, 
+            Source::across(start, self.last.expect("cannot be first").source), 
+            NodeValue::None, 
+            children
+        ));
+    }
+
+    fn parse_when(&mut self) -> Result<AstNode, AstNode> {
+        let start: Source = self.current.source;
+        self.expect(&[TokenType::KeywordWhen])?;
+        self.next();
+        let mut children: Vec<AstNode> = vec!(self.parse_condition()?);
+        children.push(self.parse_global_block()?);
+        if self.current.t == TokenType::KeywordElse {
+            self.next();
+            self.expect(&[TokenType::KeywordWhen, TokenType::BraceOpen])?;
+            if self.current.t == TokenType::BraceOpen {
+                children.push(self.parse_global_block()?);
+            } else {
+                let else_when: AstNode = self.parse_when()?;
+                children.push(self.construct_new(
+                    NodeType::Block, else_when.source,
+                    NodeValue::None, vec!(else_when)
+                ));
+            }
+        }
+        return Ok(self.construct_new(
+            NodeType::When,
+            Source::across(start, self.last.expect("cannot be first").source),
+            NodeValue::None,
+            children
+        ));
+    }
+
+    fn parse_global_block(&mut self) -> Result<AstNode, AstNode> {
+        self.expect(&[TokenType::BraceOpen])?;
+        let start: Source = self.current.source;
+        self.next();
+        let body: Vec<AstNode> = self.parse_statements(true);
+        self.expect(&[TokenType::BraceClose])?;
+        let end: Source = self.current.source;
+        self.next();
+        return Ok(self.construct_new(
+            NodeType::Block, Source::across(start, end), NodeValue::None, body
+        ));
+    }
+
+    fn parse_pattern(&mut self) -> Result<AstNode, AstNode> {
+        let is_wildcard: bool = self.current.t == TokenType::Identifier
+            && self.comp.strings.get(self.current.content) == "_";
+        if is_wildcard {
+            let wildcard: AstNode = self.construct_empty(
+                NodeType::Wildcard, self.current.source
+            );
+            self.next();
+            return Ok(wildcard);
+        }
+        let start: AstNode = self.parse_condition()?;
+        if start.t == NodeType::Call && start.children[0].t == NodeType::PathAccess {
+            return Ok(self.parse_variant_pattern(start));
+        }
+        let nt: NodeType = match self.current.t {
+            TokenType::DoubleDot => NodeType::Range,
+            TokenType::DoubleDotEqual => NodeType::InclusiveRange,
+            _ => return Ok(start)
+        };
+        self.next();
+        let end: AstNode = self.parse_condition()?;
+        return Ok(self.construct_new(
+            nt, Source::across(start.source, end.source),
+            NodeValue::None, vec!(start, end)
+        ));
+    }
+
+    fn parse_variant_pattern(&mut self, call: AstNode) -> AstNode {
+        let mut children: Vec<AstNode> = vec!(call.children[0].clone());
+        let wildcard: StringIdx = self.comp.strings.insert("_");
+        for value in &call.children[1].children {
+            let name: Option<StringIdx> = match (value.t, value.value) {
+                (NodeType::PathAccess, NodeValue::Path(p))
+                    if value.children[0].children.is_empty() =>
+                    match self.comp.paths.get(p) {
+                        [name] => Some(*name),
+                        _ => None
+                    },
+                _ => None
+            };
+            match name {
+                Some(name) if name == wildcard => children.push(
+                    self.construct_empty(NodeType::Wildcard, value.source)
+                ),
+                Some(name) => children.push(self.construct_new(
+                    NodeType::Binding, value.source, 
+                    NodeValue::String(name), Vec::new()
+                )),
+                None => self.comp.errors.push(Error::fixed(
+                    concat!(
+                        "the values of a variant can only be bound to a name ",
+                        "or ignored using '_'"
+                    ),
+                    value.source
+                ))
+            }
+        }
+        return self.construct_new(
+            NodeType::VariantPattern, call.source, NodeValue::None, children
+        );
+    }
+
+    fn parse_condition(&mut self) -> Result<AstNode, AstNode> {
+        // in 'if a == b { ... }' the block is not a struct literal for 'b'
+        let struct_literals: bool = self.struct_literals;
+        self.struct_literals = false;
+        let expr: Result<AstNode, AstNode> = self.parse_expression(usize::MAX);
+        self.struct_literals = struct_literals;
+        return expr;
+    }
+
+    fn parse_struct_literal(
+        &mut self, struct_type: AstNode
+    ) -> Result<AstNode, AstNode> {
+        self.expect(&[TokenType::BraceOpen])?;
+        self.next();
+        let mut children: Vec<AstNode> = vec!(struct_type);
+        while self.current.t != TokenType::BraceClose {
+            self.expect(&[TokenType::Identifier])?;
+            let field_start: Source = self.current.source;
+            let field_name: StringIdx = self.current.content;
+            self.next();
+            self.expect(&[TokenType::Equal])?;
+            self.next();
+            let value: AstNode = self.parse_full_expression()?;
+            children.push(self.construct_new(
+                NodeType::FieldValue,
+                Source::across(field_start, value.source),
+                NodeValue::String(field_name),
+                vec!(value)
+            ));
+            self.expect(&[TokenType::Comma, TokenType::BraceClose])?;
+            if self.current.t == TokenType::Comma { self.next(); }
+        }
+        let end: Source = self.current.source;
+        self.next();
+        return Ok(self.construct_new(
+            NodeType::StructLiteral,
+            Source::across(children[0].source, end),
+            NodeValue::None,
+            children
+        ));
     }
 
     fn parse_expression(
@@ -720,19 +1536,78 @@ impl<'c> Parser<'c> {
                             vec!(left, args_node)
                         ));
                     }
-                    TokenType::Dot => {
+                    TokenType::BracketOpen => {
                         self.next();
-                        self.expect(&[TokenType::Identifier])?;
-                        let name: StringIdx = self.current.content;
+                        let index: AstNode = if self.current.t
+                                == TokenType::DoubleDot {
+                            let zero: StringIdx = self.comp.strings.insert("0");
+                            self.construct_new(
+                                NodeType::IntegerLiteral, self.current.source,
+                                NodeValue::String(zero), Vec::new()
+                            )
+                        } else {
+                            self.parse_full_expression()?
+                        };
+                        let mut children: Vec<AstNode> = vec!(left, index);
+                        let nt: NodeType = if self.current.t 
+                                == TokenType::DoubleDot {
+                            self.next();
+                            if self.current.t != TokenType::BracketClose {
+                                children.push(self.parse_full_expression()?);
+                            }
+                            NodeType::Subslice
+                        } else {
+                            NodeType::Index
+                        };
+                        self.expect(&[TokenType::BracketClose])?;
                         let end: Source = self.current.source;
                         self.next();
                         previous = Some(self.construct_new(
-                            NodeType::MemberAccess,
-                            Source::across(left.source, end),
-                            NodeValue::String(name),
-                            vec!(left)
+                            nt, Source::across(children[0].source, end),
+                            NodeValue::None,
+                            children
                         ));
                     }
+                    TokenType::Dot => {
+                        self.next();
+                        self.expect(&[
+                            TokenType::Identifier, TokenType::Integer,
+                            TokenType::Float
+                        ])?;
+                        let end: Source = self.current.source;
+                        let content: String = String::from(
+                            self.comp.strings.get(self.current.content)
+                        );
+                        // 't.0.1' is lexed as 't', '.' and '0.1'
+                        let names: Vec<&str> = match self.current.t {
+                            TokenType::Identifier => vec!(&content),
+                            _ => content.split('.').collect()
+                        };
+                        let is_valid: bool = self.current.t == TokenType::Identifier
+                            || names.iter().all(|n| !n.is_empty()
+                                && n.chars().all(|c| c.is_ascii_digit())
+                            );
+                        if !is_valid {
+                            self.comp.errors.push(Error::dynamic(
+                                format!(
+                                    "'{}' is not a valid tuple element", content
+                                ),
+                                end
+                            ));
+                        }
+                        self.next();
+                        let mut accessed: AstNode = left;
+                        for name in names {
+                            let name: StringIdx = self.comp.strings.insert(name);
+                            accessed = self.construct_new(
+                                NodeType::MemberAccess,
+                                Source::across(accessed.source, end),
+                                NodeValue::String(name),
+                                vec!(accessed)
+                            );
+                        }
+                        previous = Some(accessed);
+                    }
                     TokenType::KeywordAs => {
                         self.next();
                         let target_t: AstNode = self.parse_type()?;
@@ -746,6 +1621,8 @@ impl<'c> Parser<'c> {
                     TokenType::Plus | TokenType::Minus |
                     TokenType::Asterisk | TokenType::Slash |
                     TokenType::Percent |
+                    TokenType::DoubleLessThan | TokenType::DoubleGreaterThan |
+                    TokenType::Ampersand | TokenType::Caret | TokenType::Pipe |
                     TokenType::LessThan | TokenType::LessThanEqual |
                     TokenType::GreaterThan | TokenType::GreaterThanEqual |
                     TokenType::DoubleEqual | TokenType::NotEqual |
@@ -756,6 +1633,11 @@ impl<'c> Parser<'c> {
                             TokenType::Asterisk => NodeType::Multiply,
                             TokenType::Slash => NodeType::Divide,
                             TokenType::Percent => NodeType::Remainder,
+                            TokenType::DoubleLessThan => NodeType::ShiftLeft,
+                            TokenType::DoubleGreaterThan => NodeType::ShiftRight,
+                            TokenType::Ampersand => NodeType::BitwiseAnd,
+                            TokenType::Caret => NodeType::BitwiseXor,
+                            TokenType::Pipe => NodeType::BitwiseOr,
                             TokenType::LessThan => NodeType::LessThan,
                             TokenType::LessThanEqual => NodeType::LessThanEqual,
                             TokenType::GreaterThan => NodeType::GreaterThan,
@@ -772,6 +1654,11 @@ impl<'c> Parser<'c> {
                             TokenType::Asterisk => PREC_MULTIPLY,
                             TokenType::Slash => PREC_DIVIDE,
                             TokenType::Percent => PREC_REMAINDER,
+                            TokenType::DoubleLessThan => PREC_SHIFT_LEFT,
+                            TokenType::DoubleGreaterThan => PREC_SHIFT_RIGHT,
+                            TokenType::Ampersand => PREC_BITWISE_AND,
+                            TokenType::Caret => PREC_BITWISE_XOR,
+                            TokenType::Pipe => PREC_BITWISE_OR,
                             TokenType::LessThan => PREC_LESS_THAN,
                             TokenType::LessThanEqual => PREC_LESS_THAN_EQUAL,
                             TokenType::GreaterThan => PREC_GREATER_THAN,
@@ -795,23 +1682,41 @@ impl<'c> Parser<'c> {
             }
             // prefix operators and literals
             match self.current.t {
+                TokenType::KeywordIf => {
+                    previous = Some(self.parse_if()?);
+                }
+                TokenType::BraceOpen => {
+                    previous = Some(self.parse_block()?);
+                }
                 TokenType::Identifier => {
                     let accessed: PathIdx = self.parse_path()?;
-                    let t_args: AstNode = self.parse_t_args()?;
-                    previous = Some(self.construct_new(
+                    let (t_args, index) = self.parse_expr_t_args()?;
+                    let mut children: Vec<AstNode> = vec!(t_args);
+                    children.extend(index);
+                    let path_access: AstNode = self.construct_new(
                         NodeType::PathAccess, 
                         Source::across(
                             start, self.last.expect("cannot be first").source
                         ),
                         NodeValue::Path(accessed),
-                        vec!(t_args)
-                    )); 
+                        children
+                    );
+                    if self.struct_literals 
+                            && self.current.t == TokenType::BraceOpen {
+                        previous = Some(self.parse_struct_literal(path_access)?);
+                    } else {
+                        previous = Some(path_access);
+                    }
                 }
-                TokenType::KeywordUnit => {
+                TokenType::KeywordUnit | TokenType::KeywordNull => {
+                    let nt: NodeType = match self.current.t {
+                        TokenType::KeywordUnit => NodeType::UnitLiteral,
+                        TokenType::KeywordNull => NodeType::NullLiteral,
+                        _ => unreachable!()
+                    };
                     self.next();
                     previous = Some(self.construct_new(
-                        NodeType::UnitLiteral, start,
-                        NodeValue::None, Vec::new()
+                        nt, start, NodeValue::None, Vec::new()
                     ));
                 }
                 TokenType::KeywordSizeof => {
@@ -826,7 +1731,7 @@ impl<'c> Parser<'c> {
                 }
                 TokenType::KeywordTrue | TokenType::KeywordFalse |
                 TokenType::Integer | TokenType::Float |
-                TokenType::String | TokenType::CString => {
+                TokenType::String | TokenType::CString | TokenType::Char => {
                     let nt: NodeType = match self.current.t {
                         TokenType::KeywordTrue | 
                         TokenType::KeywordFalse => NodeType::BooleanLiteral,
@@ -834,22 +1739,52 @@ impl<'c> Parser<'c> {
                         TokenType::Float => NodeType::FloatLiteral,
                         TokenType::String => NodeType::StringLiteral,
                         TokenType::CString => NodeType::CStringLiteral,
+                        TokenType::Char => NodeType::CharLiteral,
                         _ => unreachable!()
                     };
-                    let value: StringIdx = self.current.content;
+                    let mut value: StringIdx = self.current.content;
+                    let mut children: Vec<AstNode> = Vec::new();
+                    let is_number: bool = nt == NodeType::IntegerLiteral
+                        || nt == NodeType::FloatLiteral;
+                    let content: String = String::from(self.comp.strings.get(value));
+                    let suffix_start: Option<usize> = content
+                        .find(['u', 's', 'f'])
+                        .filter(|_| is_number);
+                    if let Some(suffix_start) = suffix_start {
+                        let suffix_t: NodeType = match &content[suffix_start..] {
+                            "u8" => NodeType::U8Type,
+                            "u16" => NodeType::U16Type,
+                            "u32" => NodeType::U32Type,
+                            "u64" => NodeType::U64Type,
+                            "usize" => NodeType::UsizeType,
+                            "s8" => NodeType::S8Type,
+                            "s16" => NodeType::S16Type,
+                            "s32" => NodeType::S32Type,
+                            "s64" => NodeType::S64Type,
+                            "f32" => NodeType::F32Type,
+                            "f64" => NodeType::F64Type,
+                            _ => NodeType::Invalid
+                        };
+                        if suffix_t != NodeType::Invalid {
+                            children.push(self.construct_empty(suffix_t, start));
+                        }
+                        value = self.comp.strings.insert(&content[..suffix_start]);
+                    }
                     self.next();
                     previous = Some(self.construct_new(
-                        nt, start, NodeValue::String(value), Vec::new()
+                        nt, start, NodeValue::String(value), children
                     ));
                 }
                 TokenType::Ampersand |
                 TokenType::Asterisk |
                 TokenType::ExclamationMark |
+                TokenType::Tilde |
                 TokenType::Minus => {
                     let nt: NodeType = match self.current.t {
                         TokenType::Ampersand => NodeType::AddressOf,
                         TokenType::Asterisk => NodeType::Deref,
                         TokenType::ExclamationMark => NodeType::LogicalNot,
+                        TokenType::Tilde => NodeType::BitwiseNot,
                         TokenType::Minus => NodeType::Negate,
                         _ => unreachable!()
                     };
@@ -857,6 +1792,7 @@ impl<'c> Parser<'c> {
                         TokenType::Ampersand => PREC_ADDRESS_OF,
                         TokenType::Asterisk => PREC_DEREF,
                         TokenType::ExclamationMark => PREC_NOT,
+                        TokenType::Tilde => PREC_BITWISE_NOT,
                         TokenType::Minus => PREC_NEGATE,
                         _ => unreachable!()
                     };
@@ -867,12 +1803,46 @@ impl<'c> Parser<'c> {
                         NodeValue::None, vec!(value)
                     ));
                 }
+                TokenType::BracketOpen => {
+                    self.next();
+                    let mut values: Vec<AstNode> = Vec::new();
+                    while self.current.t != TokenType::BracketClose {
+                        values.push(self.parse_full_expression()?);
+                        self.expect(&[
+                            TokenType::BracketClose, TokenType::Comma
+                        ])?;
+                        if self.current.t == TokenType::Comma {
+                            self.next();
+                        }
+                    }
+                    let end: Source = self.current.source;
+                    self.next();
+                    previous = Some(self.construct_new(
+                        NodeType::ArrayLiteral, Source::across(start, end),
+                        NodeValue::None, values
+                    ));
+                }
                 TokenType::ParenOpen => {
                     self.next();
                     let content: AstNode = self.parse_full_expression()?;
+                    self.expect(&[TokenType::ParenClose, TokenType::Comma])?;
+                    if self.current.t == TokenType::ParenClose {
+                        self.next();
+                        previous = Some(content);
+                        continue;
+                    }
+                    let mut values: Vec<AstNode> = vec!(content);
+                    while self.current.t == TokenType::Comma {
+                        self.next();
+                        values.push(self.parse_full_expression()?);
+                    }
                     self.expect(&[TokenType::ParenClose])?;
+                    let end: Source = self.current.source;
                     self.next();
-                    previous = Some(content);
+                    previous = Some(self.construct_new(
+                        NodeType::TupleLiteral, Source::across(start, end),
+                        NodeValue::None, values
+                    ));
                 }
                 _ => return Err(self.report_unexpected())
             }
@@ -906,6 +1876,48 @@ impl<'c> Parser<'c> {
                     children
                 ));
             }
+            TokenType::QuestionMark => {
+                self.next();
+                let ptr_type: AstNode = self.parse_type()?;
+                return Ok(self.construct_new(
+                    NodeType::NullableType,
+                    Source::across(start, ptr_type.source),
+                    NodeValue::None,
+                    vec!(ptr_type)
+                ));
+            }
+            TokenType::BracketOpen => {
+                self.next();
+                if self.current.t == TokenType::BracketClose {
+                    self.next();
+                    let mut children: Vec<AstNode> = Vec::new();
+                    if self.current.t == TokenType::KeywordConst {
+                        children.push(self.construct_empty(
+                            NodeType::IsConstant, self.current.source
+                        ));
+                        self.next();
+                    }
+                    let element_type: AstNode = self.parse_type()?;
+                    let end: Source = element_type.source;
+                    children.push(element_type);
+                    return Ok(self.construct_new(
+                        NodeType::SliceType,
+                        Source::across(start, end),
+                        NodeValue::None,
+                        children
+                    ));
+                }
+                let length: AstNode = self.parse_full_expression()?;
+                self.expect(&[TokenType::BracketClose])?;
+                self.next();
+                let element_type: AstNode = self.parse_type()?;
+                return Ok(self.construct_new(
+                    NodeType::ArrayType,
+                    Source::across(start, element_type.source),
+                    NodeValue::None,
+                    vec!(length, element_type)
+                ));
+            }
             TokenType::KeywordU8 | TokenType::KeywordU16 |
             TokenType::KeywordU32 | TokenType::KeywordU64 |
             TokenType::KeywordS8 | TokenType::KeywordS16 |
@@ -932,6 +1944,24 @@ impl<'c> Parser<'c> {
                 self.next();
                 return Ok(self.construct_empty(nt, start));
             }
+            TokenType::ParenOpen => {
+                self.next();
+                let mut elements: Vec<AstNode> = vec!(self.parse_type()?);
+                while self.current.t == TokenType::Comma {
+                    self.next();
+                    elements.push(self.parse_type()?);
+                }
+                self.expect(&[TokenType::ParenClose])?;
+                let end: Source = self.current.source;
+                self.next();
+                if elements.len() == 1 {
+                    return Ok(elements.pop().expect("has one element"));
+                }
+                return Ok(self.construct_new(
+                    NodeType::TupleType, Source::across(start, end),
+                    NodeValue::None, elements
+                ));
+            }
             TokenType::KeywordFun => {
                 self.next();
                 self.expect(&[TokenType::ParenOpen])?;
@@ -1,6 +1,9 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::{AstNode, Compiler, NodeType, NodeValue, PathIdx, StringIdx, Type};
+use crate::{
+    decl_path, AstNode, Compiler, Error, NodeType, NodeValue, PathIdx, 
+    StringIdx, Type
+};
 
 fn expand_wildcards(c: &mut Compiler, path: PathIdx) -> Vec<PathIdx> {
     let wildcard: StringIdx = c.strings.insert("*");
@@ -22,15 +25,17 @@ fn expand_wildcards(c: &mut Compiler, path: PathIdx) -> Vec<PathIdx> {
         .collect();
 }
 
-pub fn expand_paths(c: &mut Compiler) {
-    let mut files: HashMap<StringIdx, Vec<AstNode>> = c.parsed_files.clone();
-    for file in files.values_mut() {
-        expand_file_paths(c, file);
+pub fn expand_paths(c: &mut Compiler, files: &[Vec<AstNode>]) {
+    // static assertions are stored in the order of the files
+    let mut assertion_i: usize = 0;
+    for file in files {
+        expand_file_paths(c, file, &mut assertion_i);
     }
-    c.parsed_files = files;
 }
 
-fn expand_file_paths(c: &mut Compiler, file: &[AstNode]) {
+fn expand_file_paths(
+    c: &mut Compiler, file: &[AstNode], assertion_i: &mut usize
+) {
     let mut curr_mod: PathIdx = c.paths.insert(&[]);
     let mut curr_use: HashMap<StringIdx, PathIdx> = HashMap::new();
     for node in file {
@@ -50,11 +55,17 @@ fn expand_file_paths(c: &mut Compiler, file: &[AstNode]) {
                     }
                 }
             }
-            (_, NodeValue::String(name)) => {
-                let mut full_path_segs: Vec<StringIdx> = c.paths
-                    .get(curr_mod).into();
-                full_path_segs.push(name);
-                let full_path: PathIdx = c.paths.insert(&full_path_segs);
+            (NodeType::StaticAssert, _) => {
+                let mut assertion: AstNode = node.clone();
+                expand_node_paths(
+                    c, curr_mod, &curr_use, &mut HashSet::new(), &mut assertion
+                );
+                c.symbols.static_asserts_mut()[*assertion_i] = assertion;
+                *assertion_i += 1;
+            }
+            (NodeType::FunctionDecl, NodeValue::Path(_)) |
+            (_, NodeValue::String(_)) => {
+                let full_path: PathIdx = decl_path(c, curr_mod, node);
                 let mut decl_node: AstNode = AstNode::new(
                     NodeType::Invalid, node.source, NodeValue::None,
                     Vec::new(), c.types.insert(Type::Unknown)
@@ -66,7 +77,7 @@ fn expand_file_paths(c: &mut Compiler, file: &[AstNode]) {
                         .decl_node
                 );
                 expand_node_paths(
-                    c, &curr_use, &mut HashSet::new(), &mut decl_node
+                    c, curr_mod, &curr_use, &mut HashSet::new(), &mut decl_node
                 );
                 std::mem::swap(
                     &mut decl_node,
@@ -82,18 +93,25 @@ fn expand_file_paths(c: &mut Compiler, file: &[AstNode]) {
 
 fn expand_node_paths(
     c: &mut Compiler, 
+    m: PathIdx,
     u: &HashMap<StringIdx, PathIdx>,
     v: &mut HashSet<StringIdx>,
     n: &mut AstNode
 ) {
     let mut cv: HashSet<StringIdx> = v.clone();
     match (n.t, n.value) {
-        (NodeType::FunctionDecl, _) => {
-            n.children
+        (NodeType::FunctionDecl | NodeType::StructDecl, _) => {
+            let arg_lists: Vec<&AstNode> = n.children
                 .iter().filter(|c| c.t == NodeType::ArgumentList)
-                .skip(1).next().expect("should have arg list")
-                .children
-                .iter().map(|a| {
+                .collect();
+            let local_names: &[&AstNode] = if n.t == NodeType::FunctionDecl {
+                &arg_lists
+            } else {
+                &arg_lists[..1]
+            };
+            local_names
+                .iter().flat_map(|l| l.children.iter())
+                .map(|a| {
                     if let NodeValue::String(name) = a.value { name }
                     else { unreachable!("should have a value") }
                 })
@@ -104,7 +122,15 @@ fn expand_node_paths(
         _ => {}
     }
     for child in &mut n.children {
-        expand_node_paths(c, u, &mut cv, child);
+        expand_node_paths(c, m, u, &mut cv, child);
+        // the variable of a 'for' loop is only visible in its body
+        if let (NodeType::For, NodeValue::String(name)) = (n.t, n.value) {
+            cv.insert(name);
+        }
+        // values bound by a pattern are visible in the following blocks
+        if n.t == NodeType::MatchArm || n.t == NodeType::If {
+            insert_bindings(child, &mut cv);
+        }
     }
     match (n.t, n.value) {
         (NodeType::PathAccess, NodeValue::Path(rel_accessed_path)) => {
@@ -114,11 +140,20 @@ fn expand_node_paths(
                 && v.contains(&rel_accessed_segs[0]);
             if !is_local_var {
                 let alias: StringIdx = *rel_accessed_segs
-                    .last().expect("has at least one segment");
+                    .first().expect("has at least one segment");
+                let mut in_module_segs: Vec<StringIdx> = c.paths.get(m).into();
+                in_module_segs.push(alias);
+                let in_module: PathIdx = c.paths.insert(&in_module_segs);
                 let mut accessed_path_segs: Vec<StringIdx> = Vec::new();
-                accessed_path_segs.extend_from_slice(
-                    u.get(&alias).map(|p| c.paths.get(*p)).unwrap_or(&[alias])
-                );
+                if let Some(used) = u.get(&alias) {
+                    accessed_path_segs.extend_from_slice(c.paths.get(*used));
+                } else if c.symbols.symbols().contains_key(&in_module) {
+                    accessed_path_segs.extend_from_slice(&in_module_segs);
+                } else {
+                    accessed_path_segs.push(alias);
+                }
+                let rel_accessed_segs: &[StringIdx] = c.paths
+                    .get(rel_accessed_path);
                 accessed_path_segs.extend_from_slice(
                     &rel_accessed_segs[1..]
                 );
@@ -126,10 +161,51 @@ fn expand_node_paths(
                     .insert(&accessed_path_segs);
                 n.value = NodeValue::Path(accessed_path);
             }
+            let is_global_var: bool = if let NodeValue::Path(p) = n.value {
+                c.symbols.symbols().get(&p)
+                    .map(|s| s.decl_node.t == NodeType::VariableDecl)
+                    .unwrap_or(false)
+            } else { false };
+            // the parser keeps a possible index next to the template arguments
+            if is_local_var || is_global_var {
+                index_variable(c, n);
+            } else {
+                n.children.truncate(1);
+            }
         }
         (NodeType::VariableDecl, NodeValue::String(name)) => {
             v.insert(name);
         }
+        (NodeType::TupleDecl, _) => insert_bindings(n, v),
         _ => {}
     }
-}
\ No newline at end of file
+}
+
+fn insert_bindings(n: &AstNode, v: &mut HashSet<StringIdx>) {
+    match (n.t, n.value) {
+        (NodeType::Binding, NodeValue::String(name)) => { v.insert(name); }
+        (NodeType::Is | NodeType::VariantPattern | NodeType::TupleDecl, _) => {
+            n.children.iter().for_each(|c| insert_bindings(c, v));
+        }
+        _ => {}
+    }
+}
+
+fn index_variable(c: &mut Compiler, n: &mut AstNode) {
+    if n.children.len() < 2 {
+        if !n.children[0].children.is_empty() {
+            c.errors.push(Error::fixed(
+                "a variable can only be indexed with a single value",
+                n.children[0].source
+            ));
+        }
+        return;
+    }
+    let index: AstNode = n.children.pop().expect("checked above");
+    let mut indexed: AstNode = n.clone();
+    indexed.children[0].children.clear();
+    *n = AstNode::new(
+        NodeType::Index, n.source, NodeValue::None,
+        vec!(indexed, index), c.types.insert(Type::Unknown)
+    );
+}
@@ -32,11 +32,28 @@ impl Symbol {
 }
 
 
+pub(crate) fn decl_path(
+    c: &mut Compiler, module: PathIdx, node: &AstNode
+) -> PathIdx {
+    let mut full_path_segs: Vec<StringIdx> = c.paths.get(module).into();
+    match node.value {
+        NodeValue::String(name) => full_path_segs.push(name),
+        NodeValue::Path(rel) => full_path_segs.extend_from_slice(
+            c.paths.get(rel)
+        ),
+        _ => unreachable!("node must have a name")
+    }
+    return c.paths.insert(&full_path_segs);
+}
+
+
 #[derive(Debug, Clone)]
 pub struct SymbolTable {
     modules: HashSet<PathIdx>,
     symbols: HashMap<PathIdx, Symbol>,
-    unmangled: HashMap<StringIdx, PathIdx>
+    unmangled: HashMap<StringIdx, PathIdx>,
+    vtables: HashMap<(PathIdx, PathIdx), Vec<PathIdx>>,
+    static_asserts: Vec<AstNode>
 }
 
 impl SymbolTable {
@@ -44,7 +61,9 @@ impl SymbolTable {
         return SymbolTable {
             modules: HashSet::new(),
             symbols: HashMap::new(),
-            unmangled: HashMap::new()
+            unmangled: HashMap::new(),
+            vtables: HashMap::new(),
+            static_asserts: Vec::new()
         };
     }
 
@@ -54,6 +73,20 @@ impl SymbolTable {
         &mut self.symbols
     }
     pub fn unmangled(&self) -> &HashMap<StringIdx, PathIdx> { &self.unmangled }
+    // (struct, interface) -> implementations in interface declaration order
+    pub fn vtables(&self) -> &HashMap<(PathIdx, PathIdx), Vec<PathIdx>> {
+        &self.vtables
+    }
+    pub(crate) fn vtables_mut(
+        &mut self
+    ) -> &mut HashMap<(PathIdx, PathIdx), Vec<PathIdx>> {
+        &mut self.vtables
+    }
+    // assertions at the top level of all files in declaration order
+    pub fn static_asserts(&self) -> &[AstNode] { &self.static_asserts }
+    pub(crate) fn static_asserts_mut(&mut self) -> &mut Vec<AstNode> {
+        &mut self.static_asserts
+    }
 
     pub fn insert_file(&mut self, nodes: &[AstNode], c: &mut Compiler) {
         let mut curr_mod: PathIdx = c.paths.insert(&[]);
@@ -73,13 +106,25 @@ impl SymbolTable {
                     curr_mod = p;
                 }
                 (NodeType::UsageDecl, _) => {}
-                (_, NodeValue::String(name)) => {
+                (NodeType::StaticAssert, _) => {
+                    self.static_asserts.push(node.clone());
+                }
+                (NodeType::FunctionDecl, NodeValue::Path(_)) |
+                (_, NodeValue::String(_)) => {
                     let exported: bool = node.children.iter()
                         .find(|c| c.t == NodeType::IsExported).is_some();
-                    let mut full_path_segs: Vec<StringIdx> = c.paths
-                        .get(curr_mod).into();
-                    full_path_segs.push(name);
-                    let full_path: PathIdx = c.paths.insert(&full_path_segs);
+                    let full_path: PathIdx = decl_path(c, curr_mod, node);
+                    let link_name: Option<StringIdx> = node
+                        .attribute(c, "link_name")
+                        .and_then(|a| a.children.first())
+                        .and_then(|n| match n.value {
+                            NodeValue::String(name) => Some(name),
+                            _ => None
+                        });
+                    let name: StringIdx = link_name.unwrap_or_else(|| 
+                        *c.paths.get(full_path).last()
+                            .expect("has at least one segment")
+                    );
                     if exported {
                         if self.unmangled.contains_key(&name) {
                             c.errors.push(Error::dynamic(
@@ -101,12 +146,41 @@ impl SymbolTable {
                             node.source
                         ));
                     }
-                    self.symbols.insert(
-                        full_path, Symbol::from_decl_node(node.clone())
-                    );
+                    let symbol: Symbol = Symbol::from_decl_node(node.clone());
+                    if node.t == NodeType::EnumDecl {
+                        self.insert_variants(&symbol, full_path, c);
+                    }
+                    self.symbols.insert(full_path, symbol);
                 }
                 _ => unreachable!("node must be valid")
             }
         }
     }
+
+    fn insert_variants(
+        &mut self, enum_symbol: &Symbol, enum_path: PathIdx, c: &mut Compiler
+    ) {
+        for variant in &enum_symbol.decl_node.children {
+            let name: StringIdx = match (variant.t, variant.value) {
+                (NodeType::EnumVariant, NodeValue::String(n)) => n,
+                _ => continue
+            };
+            let mut variant_path_segs: Vec<StringIdx> = c.paths
+                .get(enum_path).into();
+            variant_path_segs.push(name);
+            let variant_path: PathIdx = c.paths.insert(&variant_path_segs);
+            if self.symbols.contains_key(&variant_path) {
+                c.errors.push(Error::dynamic(
+                    format!(
+                        "the variant '{}' is declared more than once",
+                        variant_path.display(c)
+                    ),
+                    variant.source
+                ));
+            }
+            let mut symbol: Symbol = Symbol::from_decl_node(variant.clone());
+            symbol.is_public = enum_symbol.is_public;
+            self.symbols.insert(variant_path, symbol);
+        }
+    }
 }
\ No newline at end of file
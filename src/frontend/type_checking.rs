@@ -1,60 +1,3862 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use crate::{
-    Compiler, AstNode, NodeType, TypeIdx, StringIdx, ScopeIdx, NodeValue
+    Compiler, AstNode, NodeType, TypeIdx, StringIdx, ScopeIdx, NodeValue,
+    PathIdx, Error, Source, Type, Scope, Variable, VariableState, Constant,
+    Constraint,
+    eval_constant, operator_interface, operator_symbol, type_layout
 };
 
+fn integer_type_range(c: &Compiler, t: NodeType) -> Option<(i128, i128)> {
+    return match t {
+        NodeType::U8Type => Some((0, u8::MAX as i128)),
+        NodeType::U16Type => Some((0, u16::MAX as i128)),
+        NodeType::U32Type => Some((0, u32::MAX as i128)),
+        NodeType::U64Type => Some((0, u64::MAX as i128)),
+        NodeType::UsizeType => integer_range(c, &Type::Usize),
+        NodeType::S8Type => Some((i8::MIN as i128, i8::MAX as i128)),
+        NodeType::S16Type => Some((i16::MIN as i128, i16::MAX as i128)),
+        NodeType::S32Type => Some((i32::MIN as i128, i32::MAX as i128)),
+        NodeType::S64Type => Some((i64::MIN as i128, i64::MAX as i128)),
+        _ => None
+    };
+}
+
+fn integer_type_bits(c: &Compiler, t: &Type) -> Option<u64> {
+    return match t {
+        Type::U8 | Type::S8 => Some(8),
+        Type::U16 | Type::S16 => Some(16),
+        Type::U32 | Type::S32 => Some(32),
+        Type::U64 | Type::S64 => Some(64),
+        Type::Usize => Some(c.pointer_width()),
+        _ => None
+    };
+}
+
+pub(crate) fn integer_range(c: &Compiler, t: &Type) -> Option<(i128, i128)> {
+    let bits: u64 = integer_type_bits(c, t)?;
+    return match t {
+        Type::S8 | Type::S16 | Type::S32 | Type::S64 => 
+            Some((-(1 << (bits - 1)), (1 << (bits - 1)) - 1)),
+        _ => Some((0, (1 << bits) - 1))
+    };
+}
+
+fn first_uncovered(covered: &[(i128, i128)], start: i128, end: i128) -> Option<i128> {
+    let mut next: i128 = start;
+    while next <= end {
+        match covered.iter().find(|(s, e)| *s <= next && next <= *e) {
+            Some((_, e)) => next = *e + 1,
+            None => return Some(next)
+        }
+    }
+    return None;
+}
+
+fn function_signature(decl_node: &AstNode) -> (&AstNode, &AstNode, &AstNode) {
+    let t_args_idx: usize = decl_node.children.iter()
+        .position(|c| c.t == NodeType::ArgumentList)
+        .expect("should have template args");
+    return (
+        &decl_node.children[t_args_idx],
+        &decl_node.children[t_args_idx + 1],
+        &decl_node.children[t_args_idx + 2]
+    );
+}
+
+pub(crate) fn substitute_type_node(
+    c: &Compiler, n: &AstNode, t_args: &HashMap<StringIdx, AstNode>
+) -> AstNode {
+    if let (NodeType::PathAccess, NodeValue::Path(p)) = (n.t, n.value) {
+        if let [name] = c.paths.get(p) {
+            if let Some(t_arg) = t_args.get(name) {
+                return t_arg.clone();
+            }
+        }
+    }
+    let mut r: AstNode = n.clone();
+    r.children = n.children.iter()
+        .map(|child| substitute_type_node(c, child, t_args))
+        .collect();
+    return r;
+}
+
+fn diverges(block: &AstNode) -> bool {
+    return match block.children.last() {
+        Some(s) => match s.t {
+            NodeType::Return | NodeType::Break | NodeType::Continue => true,
+            NodeType::Block => diverges(s),
+            _ => false
+        },
+        None => false
+    };
+}
+
+pub(crate) fn variable_type_index(decl_node: &AstNode) -> usize {
+    return decl_node.children.iter()
+        .position(|c| !matches!(
+            c.t,
+            NodeType::IsPublic | NodeType::IsExternal |
+            NodeType::IsExported | NodeType::IsConstant |
+            NodeType::DocComment | NodeType::Attribute
+        ))
+        .expect("should have a type");
+}
+
+// template arguments that keep growing are cut off at this depth
+pub(crate) const MAX_STRUCT_DEPTH: usize = 64;
+
+fn same_type_node(a: &AstNode, b: &AstNode) -> bool {
+    return a.t == b.t && a.value == b.value
+        && a.children.len() == b.children.len()
+        && a.children.iter().zip(&b.children)
+            .all(|(a, b)| same_type_node(a, b));
+}
+
+fn same_t_args(a: &[Option<AstNode>], b: &[Option<AstNode>]) -> bool {
+    return a.len() == b.len() && a.iter().zip(b).all(|(a, b)| match (a, b) {
+        (Some(a), Some(b)) => same_type_node(a, b),
+        (None, None) => true,
+        _ => false
+    });
+}
+
+fn replace_type_path(n: &AstNode, from: PathIdx, to: &AstNode) -> AstNode {
+    if n.t == NodeType::PathAccess && n.value == NodeValue::Path(from) {
+        return to.clone();
+    }
+    let mut r: AstNode = n.clone();
+    r.children = n.children.iter()
+        .map(|c| replace_type_path(c, from, to))
+        .collect();
+    return r;
+}
+
+// whether the built-in operators of a type implement the interface of
+// 'std::ops' with the given name
+fn has_builtin_operator(t: &Type, interface: &str) -> bool {
+    let is_number: bool = t.is_integer() || t.is_float();
+    return match interface {
+        "Add" | "Subtract" | "Multiply" | "Divide" | "Remainder"
+            | "Ordered" => is_number,
+        "Equal" => is_number || *t == Type::Boolean,
+        "Negate" => t.is_float() || matches!(
+            t, Type::Integer | Type::S8 | Type::S16 | Type::S32 | Type::S64
+        ),
+        _ => false
+    };
+}
+
+#[derive(Debug, Clone)]
+struct StructFrame {
+    path: PathIdx,
+    t_args: Vec<Option<AstNode>>,
+    field: Option<(StringIdx, Source)>,
+    // whether the type of the field is one of the template arguments
+    through_t_arg: bool
+}
+
 #[derive(Debug)]
 pub struct TypeChecker<'c> {
     comp: &'c mut Compiler,
     templs: Vec<HashMap<StringIdx, TypeIdx>>,
-    scopes: Vec<ScopeIdx>
+    scopes: Vec<ScopeIdx>,
+    returns: Vec<TypeIdx>,
+    // types of local variables that are known to not be null,
+    // identified by the name and source of their declaration
+    narrowed: Vec<(StringIdx, Source, TypeIdx)>
 }
 
 impl<'c> TypeChecker<'c> {
-    pub fn new(comp: &mut Compiler) -> TypeChecker {
+    pub fn new(comp: &mut Compiler) -> TypeChecker<'_> {
         return TypeChecker {
             comp,
             templs: Vec::new(),
-            scopes: Vec::new()
+            scopes: Vec::new(),
+            returns: Vec::new(),
+            narrowed: Vec::new()
         };
     }
 
+    pub(crate) fn comp(&self) -> &Compiler { return self.comp; }
+
+    // errors in declared types are reported at the declaration
+    pub(crate) fn declared_type(
+        &mut self, n: &AstNode, t_args: HashMap<StringIdx, TypeIdx>
+    ) -> TypeIdx {
+        self.templs.push(t_args);
+        let error_count: usize = self.comp.errors.len();
+        let t: TypeIdx = self.check_type(n);
+        self.comp.errors.truncate(error_count);
+        self.templs.pop();
+        return t;
+    }
+
+    fn sorted_symbols(&self, t: NodeType) -> Vec<PathIdx> {
+        let mut paths: Vec<PathIdx> = self.comp.symbols.symbols()
+            .iter()
+            .filter(|(_, s)| s.decl_node.t == t)
+            .map(|(p, _)| *p)
+            .collect();
+        paths.sort_by_key(|p| p.display(self.comp));
+        return paths;
+    }
+
     pub fn check_types(&mut self) {
-        // TODO!
+        let mut checked: HashSet<(PathIdx, String)> = HashSet::new();
+        let mut reported: HashSet<Vec<PathIdx>> = HashSet::new();
+        for path in self.sorted_symbols(NodeType::StructDecl) {
+            self.check_struct_size(
+                path, &HashMap::new(), &mut Vec::new(),
+                &mut checked, &mut reported
+            );
+        }
+        for path in self.sorted_symbols(NodeType::EnumDecl) {
+            self.check_enum_size(path, &mut Vec::new(), &mut checked, &mut reported);
+        }
+        for path in self.sorted_symbols(NodeType::EnumDecl) {
+            self.check_enum(path);
+        }
+        for path in self.sorted_symbols(NodeType::InterfaceDecl) {
+            self.check_interface(path);
+        }
+        for path in self.sorted_symbols(NodeType::StructDecl) {
+            self.check_constraint_decls(path);
+        }
+        for path in self.sorted_symbols(NodeType::FunctionDecl) {
+            self.check_constraint_decls(path);
+        }
+        for path in self.sorted_symbols(NodeType::FunctionDecl) {
+            self.check_function_owner(path);
+        }
+        for path in self.sorted_symbols(NodeType::StructDecl) {
+            self.check_implementations(path);
+        }
+        for path in self.sorted_symbols(NodeType::VariableDecl) {
+            self.check_global(path);
+        }
+        let assertions: Vec<AstNode> = self.comp.symbols.static_asserts().into();
+        for assertion in &assertions {
+            self.check_static_assert(assertion);
+        }
+        for path in self.sorted_symbols(NodeType::FunctionDecl) {
+            self.check_function(path);
+        }
+    }
+
+    fn check_interface(&mut self, path: PathIdx) {
+        let decl_node: AstNode = self.comp.symbols.symbols().get(&path)
+            .expect("should be an interface").decl_node.clone();
+        let mut names: HashSet<StringIdx> = HashSet::new();
+        for function in &decl_node.children {
+            let name: StringIdx = match (function.t, function.value) {
+                (NodeType::FunctionDecl, NodeValue::String(n)) => n,
+                _ => continue
+            };
+            if !names.insert(name) {
+                self.comp.errors.push(Error::dynamic(
+                    format!(
+                        "the function '{}::{}' is declared more than once",
+                        path.display(self.comp), self.comp.strings.get(name)
+                    ),
+                    function.source
+                ));
+            }
+            let (t_args, args, _) = function_signature(function);
+            if !t_args.children.is_empty() {
+                self.comp.errors.push(Error::dynamic(
+                    format!(
+                        concat!(
+                            "'{}::{}' is called dynamically and may ",
+                            "therefore not specify any template arguments"
+                        ),
+                        path.display(self.comp), self.comp.strings.get(name)
+                    ),
+                    t_args.source
+                ));
+            }
+            let has_receiver: bool = args.children.first()
+                .and_then(|a| a.children.last())
+                .filter(|t| t.t == NodeType::PointerType
+                    || t.t == NodeType::ReferenceType
+                )
+                .and_then(|t| t.children.last())
+                .map(|t| t.value == NodeValue::Path(path)
+                    && t.children[0].children.is_empty()
+                )
+                .unwrap_or(false);
+            if !has_receiver {
+                self.comp.errors.push(Error::dynamic(
+                    format!(
+                        concat!(
+                            "the first argument of '{}::{}' must be a ",
+                            "pointer or reference to '{}'"
+                        ),
+                        path.display(self.comp), self.comp.strings.get(name),
+                        path.display(self.comp)
+                    ),
+                    args.source
+                ));
+            }
+        }
+    }
+
+    fn check_constraint_decls(&mut self, path: PathIdx) {
+        let decl_node: AstNode = self.comp.symbols.symbols().get(&path)
+            .expect("should be a struct or function").decl_node.clone();
+        let t_params: &AstNode = decl_node.children.iter()
+            .find(|c| c.t == NodeType::ArgumentList)
+            .expect("should have template args");
+        for constraint in t_params.children.iter().flat_map(|p| &p.children) {
+            if self.constraint(constraint).is_some() { continue; }
+            self.comp.errors.push(Error::fixed(
+                concat!(
+                    "constraints must name an interface or one of the ",
+                    "categories 'integer' and 'float'"
+                ),
+                constraint.source
+            ));
+        }
+    }
+
+    fn constraint(&self, n: &AstNode) -> Option<Constraint> {
+        let NodeValue::Path(p) = n.value else { return None; };
+        match self.comp.symbols.symbols().get(&p).map(|s| s.decl_node.t) {
+            Some(NodeType::InterfaceDecl) => return Some(Constraint::Interface(p)),
+            Some(_) => return None,
+            None => {}
+        }
+        return match self.comp.paths.get(p) {
+            [name] if self.comp.strings.get(*name) == "integer" =>
+                Some(Constraint::Integer),
+            [name] if self.comp.strings.get(*name) == "float" =>
+                Some(Constraint::Float),
+            _ => None
+        };
+    }
+
+    // the name of the interface if it is one of 'std::ops'
+    fn operator_interface_name(&self, interface: PathIdx) -> Option<&str> {
+        let [std, ops, name] = self.comp.paths.get(interface) else {
+            return None;
+        };
+        if self.comp.strings.get(*std) != "std"
+            || self.comp.strings.get(*ops) != "ops" { return None; }
+        return Some(self.comp.strings.get(*name));
+    }
+
+    fn satisfies(&self, t: TypeIdx, constraint: Constraint) -> bool {
+        let operators: Option<&str> = match constraint {
+            Constraint::Interface(i) => self.operator_interface_name(i),
+            _ => None
+        };
+        return match (constraint, self.comp.types.get(t)) {
+            (_, Type::Unknown) => true,
+            // template parameters only satisfy what their constraints ensure
+            (_, Type::Param(_, constraints)) => constraints.iter()
+                .any(|c| *c == constraint || match (c, operators) {
+                    // 'integer' includes unsigned types, which cannot be negated
+                    (Constraint::Integer, Some(name)) =>
+                        has_builtin_operator(&Type::U8, name),
+                    (Constraint::Float, Some(name)) =>
+                        has_builtin_operator(&Type::F64, name),
+                    _ => false
+                }),
+            (Constraint::Integer, t) => t.is_integer(),
+            (Constraint::Float, t) => t.is_float(),
+            (Constraint::Interface(i), Type::Struct(p, _)) => self.comp.symbols
+                .vtables().contains_key(&(*p, i)),
+            (Constraint::Interface(_), t) => operators
+                .is_some_and(|name| has_builtin_operator(t, name))
+        };
+    }
+
+    fn is_integer(&self, t: TypeIdx) -> bool {
+        return match self.comp.types.get(t) {
+            Type::Param(_, constraints) =>
+                constraints.contains(&Constraint::Integer),
+            t => t.is_integer()
+        };
+    }
+
+    // errors are reported where the template arguments are passed
+    fn check_constraints(
+        &mut self, t_params: &AstNode, passed: &[(TypeIdx, Source)]
+    ) {
+        for (t_param, (passed, source)) in t_params.children.iter()
+            .zip(passed.iter()) {
+            let NodeValue::String(name) = t_param.value
+                else { unreachable!("should have a value") };
+            for constraint in &t_param.children {
+                let Some(checked) = self.constraint(constraint) else { continue; };
+                if self.satisfies(*passed, checked) { continue; }
+                let NodeValue::Path(constraint) = constraint.value
+                    else { unreachable!("should have a path") };
+                self.comp.errors.push(Error::dynamic(
+                    format!(
+                        concat!(
+                            "the type '{}' does not satisfy the constraint ",
+                            "'{}' of the template parameter '{}'"
+                        ),
+                        passed.display(self.comp), constraint.display(self.comp),
+                        self.comp.strings.get(name)
+                    ),
+                    *source
+                ));
+            }
+        }
+    }
+
+    fn check_function_owner(&mut self, path: PathIdx) {
+        let decl_node: AstNode = self.comp.symbols.symbols().get(&path)
+            .expect("should be a function").decl_node.clone();
+        if let NodeValue::String(_) = decl_node.value { return; }
+        let segs: &[StringIdx] = self.comp.paths.get(path);
+        let owner_segs: Vec<StringIdx> = segs[..segs.len() - 1].into();
+        let owner: PathIdx = self.comp.paths.insert(&owner_segs);
+        let owner_is_struct: bool = self.comp.symbols.symbols().get(&owner)
+            .map(|s| s.decl_node.t == NodeType::StructDecl)
+            .unwrap_or(false);
+        if !owner_is_struct {
+            let (_, args, _) = function_signature(&decl_node);
+            self.comp.errors.push(Error::dynamic(
+                format!(
+                    concat!(
+                        "'{}' can only be declared if '{}' is a struct ",
+                        "in the same module"
+                    ),
+                    path.display(self.comp), owner.display(self.comp)
+                ),
+                Source::across(decl_node.source, args.source)
+            ));
+        }
+    }
+
+    fn check_implementations(&mut self, path: PathIdx) {
+        let symbol = self.comp.symbols.symbols().get(&path)
+            .expect("should be a struct");
+        let decl_node: AstNode = symbol.decl_node.clone();
+        let t_arg_names: Vec<StringIdx> = symbol.template_args.clone();
+        let Some(interfaces) = decl_node.children.iter()
+            .find(|c| c.t == NodeType::InterfaceList) else { return; };
+        let unknown: TypeIdx = self.comp.types.insert(Type::Unknown);
+        let implementor: AstNode = AstNode::new(
+            NodeType::PathAccess, decl_node.source, NodeValue::Path(path),
+            vec!(AstNode::new(
+                NodeType::ArgumentList, decl_node.source, NodeValue::None,
+                t_arg_names.iter().map(|n| {
+                    let t_arg_path: PathIdx = self.comp.paths.insert(&[*n]);
+                    AstNode::new(
+                        NodeType::PathAccess, decl_node.source,
+                        NodeValue::Path(t_arg_path),
+                        vec!(AstNode::new(
+                            NodeType::ArgumentList, decl_node.source,
+                            NodeValue::None, Vec::new(), unknown
+                        )),
+                        unknown
+                    )
+                }).collect(),
+                unknown
+            )),
+            unknown
+        );
+        let mut listed: HashSet<PathIdx> = HashSet::new();
+        for interface in &interfaces.children {
+            let NodeValue::Path(interface_path) = interface.value
+                else { unreachable!("node must be valid") };
+            if !listed.insert(interface_path) {
+                self.comp.errors.push(Error::dynamic(
+                    format!(
+                        "the interface '{}' is listed more than once",
+                        interface_path.display(self.comp)
+                    ),
+                    interface.source
+                ));
+                continue;
+            }
+            let interface_decl: Option<AstNode> = self.comp.symbols.symbols()
+                .get(&interface_path)
+                .filter(|s| s.decl_node.t == NodeType::InterfaceDecl)
+                .map(|s| s.decl_node.clone());
+            let Some(interface_decl) = interface_decl else {
+                self.comp.errors.push(Error::dynamic(
+                    format!(
+                        "'{}' is not an interface",
+                        interface_path.display(self.comp)
+                    ),
+                    interface.source
+                ));
+                continue;
+            };
+            let mut vtable: Vec<PathIdx> = Vec::new();
+            let mut conforms: bool = true;
+            for required in &interface_decl.children {
+                let name: StringIdx = match (required.t, required.value) {
+                    (NodeType::FunctionDecl, NodeValue::String(n)) => n,
+                    _ => continue
+                };
+                let mut impl_path_segs: Vec<StringIdx> = self.comp.paths
+                    .get(path).into();
+                impl_path_segs.push(name);
+                let impl_path: PathIdx = self.comp.paths.insert(&impl_path_segs);
+                let implementation: Option<AstNode> = self.comp.symbols
+                    .symbols().get(&impl_path)
+                    .filter(|s| s.decl_node.t == NodeType::FunctionDecl)
+                    .map(|s| s.decl_node.clone());
+                let Some(implementation) = implementation else {
+                    self.comp.errors.push(Error::dynamic(
+                        format!(
+                            concat!(
+                                "the struct '{}' does not implement '{}', ",
+                                "which is required by the interface '{}'"
+                            ),
+                            path.display(self.comp), impl_path.display(self.comp),
+                            interface_path.display(self.comp)
+                        ),
+                        interface.source
+                    ));
+                    conforms = false;
+                    continue;
+                };
+                let expected: String = self.display_signature(
+                    required, Some((interface_path, &implementor))
+                );
+                let found: String = self.display_signature(
+                    &implementation, None
+                );
+                let (impl_t_args, impl_args, _) = function_signature(
+                    &implementation
+                );
+                let impl_t_arg_names: Vec<NodeValue> = impl_t_args.children
+                    .iter().map(|a| a.value).collect();
+                let same_t_args: bool = impl_t_arg_names == t_arg_names.iter()
+                    .map(|n| NodeValue::String(*n)).collect::<Vec<NodeValue>>();
+                if expected != found || !same_t_args {
+                    self.comp.errors.push(Error::dynamic(
+                        format!(
+                            concat!(
+                                "'{}' does not match the signature required ",
+                                "by the interface '{}' (expected '{}', ",
+                                "found '{}')"
+                            ),
+                            impl_path.display(self.comp),
+                            interface_path.display(self.comp),
+                            expected, found
+                        ),
+                        Source::across(implementation.source, impl_args.source)
+                    ));
+                    conforms = false;
+                    continue;
+                }
+                vtable.push(impl_path);
+            }
+            if conforms {
+                self.comp.symbols.vtables_mut()
+                    .insert((path, interface_path), vtable);
+            }
+        }
+    }
+
+    fn display_signature(
+        &self, decl_node: &AstNode, replaced: Option<(PathIdx, &AstNode)>
+    ) -> String {
+        let (_, args, returned) = function_signature(decl_node);
+        let display = |t: &AstNode| match replaced {
+            Some((from, to)) => self.display_type_node(
+                &replace_type_path(t, from, to)
+            ),
+            None => self.display_type_node(t)
+        };
+        return format!(
+            "fun({}): {}",
+            args.children.iter()
+                .map(|a| display(a.children.last().expect("has type")))
+                .collect::<Vec<String>>()
+                .join(", "),
+            display(returned)
+        );
+    }
+
+    fn check_enum(&mut self, path: PathIdx) {
+        let decl_node: AstNode = self.comp.symbols.symbols().get(&path)
+            .expect("should be an enum").decl_node.clone();
+        let backing_type: &AstNode = decl_node.children.iter()
+            .find(|c| c.t != NodeType::IsPublic && c.t != NodeType::DocComment)
+            .expect("should have a backing type");
+        let Some((min, max)) = integer_type_range(self.comp, backing_type.t) else {
+            self.comp.errors.push(Error::dynamic(
+                format!(
+                    concat!(
+                        "the backing type of the enum '{}' must be an ",
+                        "integer type, but is '{}'"
+                    ),
+                    path.display(self.comp),
+                    self.display_type_node(backing_type)
+                ),
+                backing_type.source
+            ));
+            return;
+        };
+        let mut used: HashMap<i128, StringIdx> = HashMap::new();
+        let mut next: Option<i128> = Some(0);
+        for variant in &decl_node.children {
+            let name: StringIdx = match (variant.t, variant.value) {
+                (NodeType::EnumVariant, NodeValue::String(n)) => n,
+                _ => continue
+            };
+            if let Some(payload) = variant.children.iter()
+                .find(|c| c.t == NodeType::VariantPayload) {
+                payload.children.iter().for_each(|t| { self.check_type(t); });
+            }
+            let value_node: Option<&AstNode> = variant.children.iter()
+                .find(|c| c.t != NodeType::VariantPayload
+                    && c.t != NodeType::DocComment
+                );
+            let value: i128 = if let Some(value_node) = value_node {
+                let Some(value) = self.eval_discriminant(value_node) else {
+                    next = None;
+                    continue;
+                };
+                value
+            } else if let Some(value) = next {
+                value
+            } else {
+                continue;
+            };
+            next = Some(value + 1);
+            if value < min || value > max {
+                self.comp.errors.push(Error::dynamic(
+                    format!(
+                        concat!(
+                            "the discriminant of '{}::{}' ({}) does not fit ",
+                            "into the backing type '{}'"
+                        ),
+                        path.display(self.comp),
+                        self.comp.strings.get(name),
+                        value,
+                        self.display_type_node(backing_type)
+                    ),
+                    variant.source
+                ));
+                continue;
+            }
+            if let Some(other) = used.get(&value) {
+                self.comp.errors.push(Error::dynamic(
+                    format!(
+                        concat!(
+                            "the variant '{}::{}' has the same discriminant ",
+                            "({}) as the variant '{}::{}'"
+                        ),
+                        path.display(self.comp),
+                        self.comp.strings.get(name),
+                        value,
+                        path.display(self.comp),
+                        self.comp.strings.get(*other)
+                    ),
+                    variant.source
+                ));
+                continue;
+            }
+            used.insert(value, name);
+        }
+    }
+
+    fn eval_discriminant(&mut self, n: &AstNode) -> Option<i128> {
+        let error_count: usize = self.comp.errors.len();
+        let checked: AstNode = self.check_node(n, None);
+        if self.comp.errors.len() > error_count { return None; }
+        match eval_constant(self.comp, &checked, &[]) {
+            Ok(Constant::Integer(value)) => return Some(value),
+            Ok(_) => {
+                self.comp.errors.push(Error::fixed(
+                    "enum discriminants must be integers", n.source
+                ));
+                return None;
+            }
+            Err(error) => {
+                self.comp.errors.push(error);
+                return None;
+            }
+        }
+    }
+
+    fn check_struct_size(
+        &mut self,
+        path: PathIdx,
+        t_args: &HashMap<StringIdx, AstNode>,
+        stack: &mut Vec<StructFrame>,
+        checked: &mut HashSet<(PathIdx, String)>,
+        reported: &mut HashSet<Vec<PathIdx>>
+    ) {
+        let decl_node: AstNode = self.comp.symbols.symbols().get(&path)
+            .expect("should be a struct").decl_node.clone();
+        let mut arg_lists = decl_node.children.iter()
+            .filter(|c| c.t == NodeType::ArgumentList);
+        let t_arg_names: Vec<StringIdx> = arg_lists.next()
+            .expect("should have template args").children.iter()
+            .map(|a| if let NodeValue::String(n) = a.value { n }
+                else { unreachable!("must have correct template args!") }
+            )
+            .collect();
+        let fields: &AstNode = arg_lists.next().expect("should have fields");
+        let passed_t_args: Vec<Option<AstNode>> = t_arg_names.iter()
+            .map(|n| t_args.get(n).cloned())
+            .collect();
+        // a struct that contains itself with the same template arguments,
+        // or that reaches itself without going through any template
+        // argument, would have to contain itself again and again
+        let cycle_start: Option<usize> = (0..stack.len()).find(|i|
+            stack[*i].path == path && (
+                same_t_args(&stack[*i].t_args, &passed_t_args)
+                    || stack[*i..].iter().all(|f| !f.through_t_arg)
+                    || stack.len() > MAX_STRUCT_DEPTH
+            )
+        );
+        if let Some(cycle_start) = cycle_start {
+            self.report_struct_cycle(&stack[cycle_start..], reported);
+            return;
+        }
+        let t_args_key: String = passed_t_args.iter()
+            .map(|t| t.as_ref()
+                .map(|t| self.display_type_node(t))
+                .unwrap_or_default()
+            )
+            .collect::<Vec<String>>()
+            .join(", ");
+        let key: (PathIdx, String) = (path, t_args_key.clone());
+        if checked.contains(&key) { return; }
+        checked.insert(key);
+        for field in &fields.children {
+            let field_name: StringIdx = if let NodeValue::String(n)
+                = field.value { n }
+                else { unreachable!("fields must have names") };
+            let field_type: &AstNode = field.children.last()
+                .expect("fields must have a type");
+            stack.push(StructFrame {
+                path, 
+                t_args: passed_t_args.clone(),
+                field: Some((field_name, field.source)),
+                through_t_arg: false
+            });
+            self.check_field_size(
+                field_type, t_args, stack, checked, reported
+            );
+            stack.pop();
+        }
+    }
+
+    fn check_field_size(
+        &mut self,
+        field_type: &AstNode,
+        t_args: &HashMap<StringIdx, AstNode>,
+        stack: &mut Vec<StructFrame>,
+        checked: &mut HashSet<(PathIdx, String)>,
+        reported: &mut HashSet<Vec<PathIdx>>
+    ) {
+        let path: PathIdx = match (field_type.t, field_type.value) {
+            (NodeType::PathAccess, NodeValue::Path(p)) => p,
+            (NodeType::ArrayType, _) => {
+                self.check_field_size(
+                    &field_type.children[1], t_args, stack, checked, reported
+                );
+                return;
+            }
+            (NodeType::TupleType, _) => {
+                for element in &field_type.children {
+                    self.check_field_size(
+                        element, t_args, stack, checked, reported
+                    );
+                }
+                return;
+            }
+            _ => return
+        };
+        if let [name] = self.comp.paths.get(path) {
+            if let Some(t_arg) = t_args.get(name) {
+                let t_arg: AstNode = t_arg.clone();
+                let through_t_arg: bool = match stack.last_mut() {
+                    Some(f) => std::mem::replace(&mut f.through_t_arg, true),
+                    None => false
+                };
+                self.check_field_size(
+                    &t_arg, &HashMap::new(), stack, checked, reported
+                );
+                if let Some(f) = stack.last_mut() {
+                    f.through_t_arg = through_t_arg;
+                }
+                return;
+            }
+        }
+        match self.comp.symbols.symbols().get(&path).map(|s| s.decl_node.t) {
+            Some(NodeType::StructDecl) => {}
+            Some(NodeType::EnumDecl) => {
+                self.check_enum_size(path, stack, checked, reported);
+                return;
+            }
+            _ => return
+        }
+        let t_arg_names: Vec<StringIdx> = self.comp.symbols.symbols()
+            .get(&path).expect("checked above").template_args.clone();
+        let passed_t_args: HashMap<StringIdx, AstNode> = t_arg_names.iter()
+            .zip(field_type.children[0].children.iter())
+            .map(|(n, t)| (*n, substitute_type_node(self.comp, t, t_args)))
+            .collect();
+        self.check_struct_size(path, &passed_t_args, stack, checked, reported);
+    }
+
+    fn check_enum_size(
+        &mut self,
+        path: PathIdx,
+        stack: &mut Vec<StructFrame>,
+        checked: &mut HashSet<(PathIdx, String)>,
+        reported: &mut HashSet<Vec<PathIdx>>
+    ) {
+        let decl_node: AstNode = self.comp.symbols.symbols().get(&path)
+            .expect("should be an enum").decl_node.clone();
+        let cycle_start: Option<usize> = stack.iter()
+            .position(|f| f.path == path);
+        if let Some(cycle_start) = cycle_start {
+            self.report_struct_cycle(&stack[cycle_start..], reported);
+            return;
+        }
+        let key: (PathIdx, String) = (path, String::new());
+        if checked.contains(&key) { return; }
+        checked.insert(key);
+        for variant in &decl_node.children {
+            let name: StringIdx = match (variant.t, variant.value) {
+                (NodeType::EnumVariant, NodeValue::String(n)) => n,
+                _ => continue
+            };
+            let Some(payload) = variant.children.iter()
+                .find(|c| c.t == NodeType::VariantPayload) else { continue; };
+            for value_type in &payload.children {
+                stack.push(StructFrame {
+                    path,
+                    t_args: Vec::new(),
+                    field: Some((name, payload.source)),
+                    through_t_arg: false
+                });
+                self.check_field_size(
+                    value_type, &HashMap::new(), stack, checked, reported
+                );
+                stack.pop();
+            }
+        }
+    }
+
+    fn report_struct_cycle(
+        &mut self, 
+        cycle: &[StructFrame], 
+        reported: &mut HashSet<Vec<PathIdx>>
+    ) {
+        let mut involved: Vec<PathIdx> = cycle.iter().map(|f| f.path)
+            .collect();
+        involved.sort_by_key(|p| p.display(self.comp));
+        involved.dedup();
+        if reported.contains(&involved) { return; }
+        reported.insert(involved);
+        let mut cycle_str: String = String::new();
+        let is_enum = |tc: &Self, p: PathIdx| tc.comp.symbols.symbols().get(&p)
+            .map(|s| s.decl_node.t == NodeType::EnumDecl)
+            .unwrap_or(false);
+        for frame in cycle {
+            let (field_name, _) = frame.field.expect("should have a field");
+            cycle_str.push_str(&frame.path.display(self.comp));
+            cycle_str.push_str(if is_enum(self, frame.path) { "::" } else { "." });
+            cycle_str.push_str(self.comp.strings.get(field_name));
+            cycle_str.push_str(" -> ");
+        }
+        cycle_str.push_str(&cycle[0].path.display(self.comp));
+        let (_, field_source) = cycle[0].field.expect("should have a field");
+        self.comp.errors.push(Error::dynamic(
+            format!(
+                concat!(
+                    "the {} '{}' has an infinite size, because it ",
+                    "contains itself ({}); consider using a pointer instead"
+                ),
+                if is_enum(self, cycle[0].path) { "enum" } else { "struct" },
+                cycle[0].path.display(self.comp), cycle_str
+            ),
+            field_source
+        ));
+    }
+
+    pub fn display_type_node(&self, n: &AstNode) -> String {
+        let list = |tc: &Self, l: &AstNode| l.children.iter()
+            .map(|c| tc.display_type_node(c))
+            .collect::<Vec<String>>()
+            .join(", ");
+        let is_const: bool = n.children.iter()
+            .find(|c| c.t == NodeType::IsConstant).is_some();
+        return match (n.t, n.value) {
+            (NodeType::PointerType | NodeType::ReferenceType, _) => format!(
+                "{}{}{}",
+                if n.t == NodeType::PointerType { "*" } else { "&" },
+                if is_const { "const " } else { "" },
+                self.display_type_node(n.children.last().expect("has type"))
+            ),
+            (NodeType::ArrayType, _) => format!(
+                "[{}]{}",
+                match (n.children[0].t, n.children[0].value) {
+                    (NodeType::IntegerLiteral, NodeValue::String(l)) => 
+                        String::from(self.comp.strings.get(l)),
+                    (NodeType::PathAccess, NodeValue::Path(p)) => 
+                        p.display(self.comp),
+                    _ => String::from("...")
+                },
+                self.display_type_node(&n.children[1])
+            ),
+            (NodeType::NullableType, _) => format!(
+                "?{}", self.display_type_node(&n.children[0])
+            ),
+            (NodeType::SliceType, _) => format!(
+                "[]{}{}",
+                if is_const { "const " } else { "" },
+                self.display_type_node(n.children.last().expect("has type"))
+            ),
+            (NodeType::U8Type, _) => String::from("u8"),
+            (NodeType::U16Type, _) => String::from("u16"),
+            (NodeType::U32Type, _) => String::from("u32"),
+            (NodeType::U64Type, _) => String::from("u64"),
+            (NodeType::UsizeType, _) => String::from("usize"),
+            (NodeType::S8Type, _) => String::from("s8"),
+            (NodeType::S16Type, _) => String::from("s16"),
+            (NodeType::S32Type, _) => String::from("s32"),
+            (NodeType::S64Type, _) => String::from("s64"),
+            (NodeType::F32Type, _) => String::from("f32"),
+            (NodeType::F64Type, _) => String::from("f64"),
+            (NodeType::UnitType, _) => String::from("unit"),
+            (NodeType::BoolType, _) => String::from("bool"),
+            (NodeType::InferredType, _) => n.rtype.display(self.comp),
+            (NodeType::TupleType, _) => format!("({})", list(self, n)),
+            (NodeType::FunctionType, _) => format!(
+                "fun({}): {}",
+                list(self, &n.children[0]),
+                self.display_type_node(&n.children[1])
+            ),
+            (NodeType::PathAccess, NodeValue::Path(p)) => {
+                if n.children[0].children.is_empty() {
+                    p.display(self.comp)
+                } else {
+                    format!("{}[{}]", p.display(self.comp), list(self, &n.children[0]))
+                }
+            }
+            _ => String::from("<invalid>")
+        };
+    }
+
+    fn check_global(&mut self, path: PathIdx) {
+        let decl_node: AstNode = self.comp.symbols.symbols().get(&path)
+            .expect("should be a variable").decl_node.clone();
+        let type_i: usize = variable_type_index(&decl_node);
+        let value_type: TypeIdx = self.check_type(&decl_node.children[type_i]);
+        let Some(value) = decl_node.children.get(type_i + 1) else { return; };
+        let value: AstNode = self.check_node(value, Some(value_type));
+        self.expect_type(value_type, &value);
+        self.comp.symbols.symbols_mut().get_mut(&path)
+            .expect("should still exist").decl_node.children[type_i + 1] = value;
+    }
+
+    fn check_static_assert(&mut self, n: &AstNode) -> AstNode {
+        let boolean: TypeIdx = self.comp.types.insert(Type::Boolean);
+        let unit: TypeIdx = self.comp.types.insert(Type::Unit);
+        let error_count: usize = self.comp.errors.len();
+        let condition: AstNode = self.check_node(&n.children[0], Some(boolean));
+        self.expect_type(boolean, &condition);
+        let mut children: Vec<AstNode> = n.children.clone();
+        children[0] = condition;
+        let checked: AstNode = AstNode::new(n.t, n.source, n.value, children, unit);
+        // conditions that are not valid cannot be evaluated
+        if self.comp.errors.len() > error_count { return checked; }
+        match eval_constant(
+            self.comp, &checked.children[0], &self.scopes
+        ) {
+            Ok(Constant::Boolean(true)) => {}
+            Ok(_) => {
+                let message: String = match checked.children.get(1)
+                    .map(|m| m.value) {
+                    Some(NodeValue::String(m)) => 
+                        String::from(self.comp.strings.get(m)),
+                    _ => String::from("the static assertion failed")
+                };
+                self.comp.errors.push(Error::dynamic(message, n.source));
+            }
+            Err(error) => self.comp.errors.push(error)
+        }
+        return checked;
+    }
+
+    fn check_function(&mut self, path: PathIdx) {
+        let decl_node: AstNode = self.comp.symbols.symbols().get(&path)
+            .expect("should be a function").decl_node.clone();
+        let (t_args, args, returned) = function_signature(&decl_node);
+        // template parameters are only known by their constraints
+        let templs: HashMap<StringIdx, TypeIdx> = t_args.children.iter()
+            .map(|a| {
+                let NodeValue::String(name) = a.value
+                    else { unreachable!("should have a value") };
+                let constraints: Vec<Constraint> = a.children.iter()
+                    .filter_map(|c| self.constraint(c))
+                    .collect();
+                (name, self.comp.types.insert(Type::Param(name, constraints)))
+            })
+            .collect();
+        self.templs.push(templs);
+        let return_type: TypeIdx = self.check_type(returned);
+        let mut scope: Scope = Scope::new();
+        for arg in &args.children {
+            let NodeValue::String(name) = arg.value
+                else { unreachable!("should have a value") };
+            let arg_type: TypeIdx = self.check_type(
+                arg.children.last().expect("should have a type")
+            );
+            scope.insert(
+                name, arg.source, VariableState::Initialized, arg_type, false
+            );
+        }
+        // template functions are checked once, against their constraints
+        let Some(body_i) = decl_node.children.iter()
+            .position(|c| c.t == NodeType::Block) else {
+            self.templs.pop();
+            return;
+        };
+        self.scopes.push(self.comp.scopes.insert(scope));
+        self.returns.push(return_type);
+        let body: AstNode = self.check_node(&decl_node.children[body_i], None);
+        self.returns.pop();
+        self.scopes.pop();
+        self.templs.pop();
+        self.comp.symbols.symbols_mut().get_mut(&path)
+            .expect("should still exist").decl_node.children[body_i] = body;
+    }
+
+    // interfaces have no size, so they are only valid as pointees
+    fn check_pointee(&mut self, n: &AstNode) -> TypeIdx {
+        if let (NodeType::PathAccess, NodeValue::Path(p)) = (n.t, n.value) {
+            let is_interface: bool = self.comp.symbols.symbols().get(&p)
+                .is_some_and(|s| s.decl_node.t == NodeType::InterfaceDecl);
+            if is_interface {
+                return self.comp.types.insert(Type::Interface(p));
+            }
+        }
+        return self.check_type(n);
+    }
+
+    pub fn check_type(&mut self, n: &AstNode) -> TypeIdx {
+        let is_const: bool = n.children.iter()
+            .find(|c| c.t == NodeType::IsConstant).is_some();
+        let t: Type = match (n.t, n.value) {
+            (NodeType::PointerType, _) => Type::Pointer(
+                is_const, self.check_pointee(n.children.last().expect("has type"))
+            ),
+            (NodeType::ReferenceType, _) => Type::Reference(
+                is_const, self.check_pointee(n.children.last().expect("has type"))
+            ),
+            (NodeType::NullableType, _) => {
+                let pointer: &AstNode = &n.children[0];
+                let pointer_type: TypeIdx = self.check_type(pointer);
+                if pointer.t == NodeType::PointerType {
+                    Type::Nullable(pointer_type)
+                } else {
+                    self.comp.errors.push(Error::dynamic(
+                        format!(
+                            "only pointers can be nullable, but '{}' is not a pointer",
+                            self.display_type_node(pointer)
+                        ),
+                        pointer.source
+                    ));
+                    Type::Unknown
+                }
+            }
+            (NodeType::ArrayType, _) => {
+                let element_type: TypeIdx = self.check_type(&n.children[1]);
+                let length: Option<u64> = match n.children[0].value {
+                    NodeValue::String(l)
+                        if n.children[0].t == NodeType::IntegerLiteral =>
+                        self.comp.strings.get(l).parse().ok(),
+                    _ => None
+                };
+                match length {
+                    Some(length) => Type::Array(length, element_type),
+                    None => {
+                        self.comp.errors.push(Error::fixed(
+                            "the length of an array must be an integer literal",
+                            n.children[0].source
+                        ));
+                        Type::Unknown
+                    }
+                }
+            }
+            (NodeType::SliceType, _) => Type::Slice(
+                is_const, self.check_type(n.children.last().expect("has type"))
+            ),
+            (NodeType::U8Type, _) => Type::U8,
+            (NodeType::U16Type, _) => Type::U16,
+            (NodeType::U32Type, _) => Type::U32,
+            (NodeType::U64Type, _) => Type::U64,
+            (NodeType::UsizeType, _) => Type::Usize,
+            (NodeType::S8Type, _) => Type::S8,
+            (NodeType::S16Type, _) => Type::S16,
+            (NodeType::S32Type, _) => Type::S32,
+            (NodeType::S64Type, _) => Type::S64,
+            (NodeType::F32Type, _) => Type::F32,
+            (NodeType::F64Type, _) => Type::F64,
+            (NodeType::UnitType, _) => Type::Unit,
+            (NodeType::BoolType, _) => Type::Boolean,
+            // TODO: function types
+            (NodeType::FunctionType, _) => Type::Unknown,
+            (NodeType::InferredType, _) => return n.rtype,
+            (NodeType::TupleType, _) => Type::Tuple(
+                n.children.iter().map(|e| self.check_type(e)).collect()
+            ),
+            (NodeType::PathAccess, NodeValue::Path(p)) => {
+                if let [name] = self.comp.paths.get(p) {
+                    let t_arg: Option<TypeIdx> = self.templs.last()
+                        .and_then(|t| t.get(name)).copied();
+                    if let Some(t_arg) = t_arg { return t_arg; }
+                }
+                let passed_t_args: Vec<TypeIdx> = n.children[0].children.iter()
+                    .map(|t| self.check_type(t))
+                    .collect();
+                match self.comp.symbols.symbols().get(&p).map(|s| s.decl_node.t) {
+                    Some(NodeType::StructDecl) => {
+                        let t_params: AstNode = self.comp.symbols.symbols()
+                            .get(&p).expect("checked above").decl_node.children
+                            .iter().find(|c| c.t == NodeType::ArgumentList)
+                            .expect("should have template args").clone();
+                        if t_params.children.len() != passed_t_args.len() {
+                            self.comp.errors.push(Error::dynamic(
+                                format!(
+                                    concat!(
+                                        "'{}' expects {} template argument{}, ",
+                                        "but got {}"
+                                    ),
+                                    p.display(self.comp),
+                                    t_params.children.len(),
+                                    if t_params.children.len() == 1 { "" }
+                                        else { "s" },
+                                    passed_t_args.len()
+                                ),
+                                n.source
+                            ));
+                            return self.comp.types.insert(Type::Unknown);
+                        }
+                        let passed: Vec<(TypeIdx, Source)> = passed_t_args
+                            .iter().copied()
+                            .zip(n.children[0].children.iter().map(|t| t.source))
+                            .collect();
+                        self.check_constraints(&t_params, &passed);
+                        Type::Struct(p, passed_t_args)
+                    }
+                    Some(NodeType::InterfaceDecl) => {
+                        self.comp.errors.push(Error::dynamic(
+                            format!(
+                                concat!(
+                                    "the interface '{}' can only be used ",
+                                    "behind a pointer or a reference"
+                                ),
+                                p.display(self.comp)
+                            ),
+                            n.source
+                        ));
+                        Type::Unknown
+                    }
+                    Some(NodeType::EnumDecl) => Type::Enum(p),
+                    Some(_) => {
+                        self.comp.errors.push(Error::dynamic(
+                            format!(
+                                "the symbol '{}' is not a type", 
+                                p.display(self.comp)
+                            ),
+                            n.source
+                        ));
+                        Type::Unknown
+                    }
+                    None => {
+                        self.comp.errors.push(Error::dynamic(
+                            format!(
+                                "the symbol '{}' does not exist",
+                                p.display(self.comp)
+                            ),
+                            n.source
+                        ));
+                        Type::Unknown
+                    }
+                }
+            }
+            _ => Type::Unknown
+        };
+        return self.comp.types.insert(t);
+    }
+
+    // field names and types with the template arguments filled in
+    fn field_types(
+        &mut self, path: PathIdx, t_args: &[TypeIdx]
+    ) -> Vec<(StringIdx, TypeIdx)> {
+        let decl_node: AstNode = self.comp.symbols.symbols().get(&path)
+            .expect("should be a struct").decl_node.clone();
+        let mut arg_lists = decl_node.children.iter()
+            .filter(|c| c.t == NodeType::ArgumentList);
+        let t_params: &AstNode = arg_lists.next().expect("should have t-args");
+        let fields: &AstNode = arg_lists.next().expect("should have fields");
+        let unknown: TypeIdx = self.comp.types.insert(Type::Unknown);
+        let templs: HashMap<StringIdx, TypeIdx> = t_params.children.iter()
+            .enumerate()
+            .map(|(i, a)| if let NodeValue::String(name) = a.value {
+                    (name, t_args.get(i).copied().unwrap_or(unknown))
+                } else { unreachable!("should have a value") }
+            )
+            .collect();
+        return fields.children.iter()
+            .map(|f| {
+                let NodeValue::String(name) = f.value
+                    else { unreachable!("fields must have names") };
+                let field_type: TypeIdx = self.declared_type(
+                    f.children.last().expect("fields must have a type"),
+                    templs.clone()
+                );
+                (name, field_type)
+            })
+            .collect();
+    }
+
+    fn check_field(
+        &mut self, path: PathIdx, t_args: &[TypeIdx], name: StringIdx,
+        source: Source
+    ) -> TypeIdx {
+        let field_type: Option<TypeIdx> = self.field_types(path, t_args)
+            .into_iter()
+            .find(|(f, _)| *f == name)
+            .map(|(_, t)| t);
+        if let Some(field_type) = field_type { return field_type; }
+        self.comp.errors.push(Error::dynamic(
+            format!(
+                "the struct '{}' has no field '{}'",
+                path.display(self.comp), self.comp.strings.get(name)
+            ),
+            source
+        ));
+        return self.comp.types.insert(Type::Unknown);
+    }
+
+    fn report_missing_field(
+        &mut self, t: TypeIdx, name: StringIdx, source: Source
+    ) -> TypeIdx {
+        let unknown: TypeIdx = self.comp.types.insert(Type::Unknown);
+        if t == unknown { return unknown; }
+        self.comp.errors.push(Error::dynamic(
+            format!(
+                "the type '{}' has no field '{}'",
+                t.display(self.comp), self.comp.strings.get(name)
+            ),
+            source
+        ));
+        return unknown;
+    }
+
+    fn check_element(
+        &mut self, tuple: TypeIdx, element: StringIdx, source: Source
+    ) -> TypeIdx {
+        let elements: Vec<TypeIdx> = match self.comp.types.get(tuple) {
+            Type::Tuple(elements) => elements.clone(),
+            _ => unreachable!("should be a tuple")
+        };
+        let index: Option<usize> = self.comp.strings.get(element).parse().ok();
+        if let Some(element) = index.and_then(|i| elements.get(i)) {
+            return *element;
+        }
+        self.comp.errors.push(Error::dynamic(
+            format!(
+                "the tuple '{}' has no element '{}'",
+                tuple.display(self.comp), self.comp.strings.get(element)
+            ),
+            source
+        ));
+        return self.comp.types.insert(Type::Unknown);
+    }
+
+    fn find_variable(&self, path: PathIdx) -> Option<Variable> {
+        let [name] = self.comp.paths.get(path) else { return None; };
+        return self.find_local(*name);
+    }
+
+    fn find_local(&self, name: StringIdx) -> Option<Variable> {
+        return self.scopes.iter().rev()
+            .find_map(|s| self.comp.scopes.get(*s).get_last(name))
+            .copied();
+    }
+
+    fn local_variable(&self, n: &AstNode) -> Option<(StringIdx, Variable)> {
+        let (NodeType::PathAccess, NodeValue::Path(p)) = (n.t, n.value)
+            else { return None; };
+        let [name] = self.comp.paths.get(p) else { return None; };
+        return self.find_local(*name).map(|v| (*name, v));
+    }
+
+    // why the checked value may not be modified, if it may not
+    fn immutability(&self, n: &AstNode) -> Option<String> {
+        let behind_const: &str
+            = "values behind a constant pointer cannot be modified";
+        match (n.t, n.value) {
+            _ if *self.comp.types.get(n.rtype) == Type::Unknown => return None,
+            (NodeType::PathAccess, NodeValue::Path(p)) => {
+                let is_constant: bool = match self.find_variable(p) {
+                    Some(variable) => variable.is_constant,
+                    None => self.comp.symbols.symbols().get(&p)
+                        .map(|s| s.decl_node.t != NodeType::VariableDecl
+                            || s.decl_node.children.iter()
+                                .any(|c| c.t == NodeType::IsConstant)
+                        )
+                        .unwrap_or(false)
+                };
+                if !is_constant { return None; }
+                return Some(format!(
+                    "the constant '{}' cannot be modified", p.display(self.comp)
+                ));
+            }
+            (NodeType::MemberAccess, NodeValue::String(member))
+                if self.comp.strings.get(member) == "len"
+                    && matches!(
+                        self.comp.types.get(n.children[0].rtype),
+                        Type::Array(_, _) | Type::Slice(_, _)
+                    ) => {
+                return Some(String::from(
+                    "the length of an array or slice cannot be modified"
+                ));
+            }
+            (NodeType::MemberAccess | NodeType::Index | NodeType::Deref, _) => {
+                let accessed: &AstNode = &n.children[0];
+                return match *self.comp.types.get(accessed.rtype) {
+                    Type::Pointer(true, _) | Type::Reference(true, _)
+                        | Type::Slice(true, _) => Some(String::from(behind_const)),
+                    Type::Pointer(false, _) | Type::Reference(false, _)
+                        | Type::Slice(false, _) => None,
+                    _ => self.immutability(accessed)
+                };
+            }
+            _ => return Some(String::from("temporary values cannot be modified"))
+        }
+    }
+
+    fn check_assignable(&mut self, target: &AstNode) {
+        if let Some(reason) = self.immutability(target) {
+            self.comp.errors.push(Error::dynamic(reason, target.source));
+        }
+    }
+
+    fn narrowed_type(&self, name: StringIdx, variable: &Variable) -> TypeIdx {
+        return self.narrowed.iter().rev()
+            .find(|(n, s, _)| *n == name && *s == variable.source)
+            .map(|(_, _, t)| *t)
+            .unwrap_or(variable.value_type);
+    }
+
+    fn narrowings(
+        &self, condition: &AstNode, when: bool
+    ) -> Vec<(StringIdx, Source, TypeIdx)> {
+        match condition.t {
+            NodeType::LogicalNot => {
+                return self.narrowings(&condition.children[0], !when);
+            }
+            NodeType::LogicalAnd | NodeType::LogicalOr
+                    if when == (condition.t == NodeType::LogicalAnd) => {
+                let mut narrowed: Vec<(StringIdx, Source, TypeIdx)> = self
+                    .narrowings(&condition.children[0], when);
+                narrowed.extend(self.narrowings(&condition.children[1], when));
+                return narrowed;
+            }
+            NodeType::Equal | NodeType::NotEqual
+                if when == (condition.t == NodeType::NotEqual) => {}
+            _ => return Vec::new()
+        }
+        let [left, right] = &condition.children[..] else { return Vec::new(); };
+        let value: &AstNode = if right.t == NodeType::NullLiteral { left }
+            else if left.t == NodeType::NullLiteral { right }
+            else { return Vec::new(); };
+        let Some((name, variable)) = self.local_variable(value)
+            else { return Vec::new(); };
+        match *self.comp.types.get(variable.value_type) {
+            Type::Nullable(pointer) => {
+                return vec!((name, variable.source, pointer));
+            }
+            _ => return Vec::new()
+        }
+    }
+
+    fn assigned_variables(&self, n: &AstNode, names: &mut Vec<StringIdx>) {
+        let is_assignment: bool = matches!(
+            n.t,
+            NodeType::Assign | NodeType::AssignAdd | NodeType::AssignSubtract
+                | NodeType::AssignMultiply | NodeType::AssignDivide
+                | NodeType::AssignRemainder | NodeType::AssignBitwiseAnd
+                | NodeType::AssignBitwiseOr | NodeType::AssignBitwiseXor
+                | NodeType::AssignShiftLeft | NodeType::AssignShiftRight
+        );
+        // taking the address of a variable allows assigning it later
+        let target: Option<NodeValue> = n.children.first()
+            .filter(|_| is_assignment || n.t == NodeType::AddressOf)
+            .map(|t| t.value);
+        if let Some(NodeValue::Path(p)) = target {
+            if let [name] = self.comp.paths.get(p) { names.push(*name); }
+        }
+        for child in &n.children {
+            self.assigned_variables(child, names);
+        }
+    }
+
+    fn forget_narrowings(&mut self, n: &AstNode) {
+        let mut names: Vec<StringIdx> = Vec::new();
+        self.assigned_variables(n, &mut names);
+        for name in names {
+            let Some(variable) = self.find_local(name) else { continue; };
+            if self.narrowed_type(name, &variable) == variable.value_type {
+                continue;
+            }
+            self.narrowed.push((name, variable.source, variable.value_type));
+        }
+    }
+
+    fn narrow_after_if(
+        &mut self, condition: &AstNode, body: &AstNode, otherwise: Option<&AstNode>
+    ) {
+        self.forget_narrowings(body);
+        if let Some(otherwise) = otherwise { self.forget_narrowings(otherwise); }
+        // code after the 'if' is only reached through the branch that
+        // is not left early, which may not assign the narrowed variables
+        let (when, reached): (bool, Option<&AstNode>) = match otherwise {
+            _ if diverges(body) => (false, otherwise),
+            Some(otherwise) if diverges(otherwise) => (true, Some(body)),
+            _ => return
+        };
+        let mut assigned: Vec<StringIdx> = Vec::new();
+        if let Some(reached) = reached {
+            self.assigned_variables(reached, &mut assigned);
+        }
+        let narrowed: Vec<(StringIdx, Source, TypeIdx)> = self
+            .narrowings(condition, when).into_iter()
+            .filter(|(name, _, _)| !assigned.contains(name))
+            .collect();
+        self.narrowed.extend(narrowed);
+    }
+
+    fn report_nullable(&mut self, value: &AstNode) {
+        self.comp.errors.push(Error::dynamic(
+            format!(
+                concat!(
+                    "a value of type '{}' may be null, so it needs to be ",
+                    "compared to 'null' before it can be used"
+                ),
+                value.rtype.display(self.comp)
+            ),
+            value.source
+        ));
+    }
+
+    fn variant_payload(&mut self, variant: PathIdx) -> Option<Vec<TypeIdx>> {
+        let payload: AstNode = self.comp.symbols.symbols().get(&variant)?
+            .decl_node.children.iter()
+            .find(|c| c.t == NodeType::VariantPayload)?.clone();
+        // errors in the payload are reported at the declaration
+        let error_count: usize = self.comp.errors.len();
+        let types: Vec<TypeIdx> = payload.children.iter()
+            .map(|t| self.check_type(t))
+            .collect();
+        self.comp.errors.truncate(error_count);
+        return Some(types);
+    }
+
+    fn carries_values(&self, enum_path: PathIdx) -> bool {
+        return self.comp.symbols.symbols().get(&enum_path)
+            .map(|s| s.decl_node.children.iter().any(|v| v.children.iter()
+                .any(|c| c.t == NodeType::VariantPayload)
+            ))
+            .unwrap_or(false);
+    }
+
+    fn find_function(&mut self, path: PathIdx) -> Option<AstNode> {
+        if let Some(symbol) = self.comp.symbols.symbols().get(&path) {
+            if symbol.decl_node.t != NodeType::FunctionDecl { return None; }
+            return Some(symbol.decl_node.clone());
+        }
+        // functions of interfaces are not symbols, but may still be called
+        let (name, owner) = self.comp.paths.get(path).split_last()?;
+        let (name, owner) = (*name, owner.to_vec());
+        let owner: PathIdx = self.comp.paths.insert(&owner);
+        let owner: &AstNode = &self.comp.symbols.symbols().get(&owner)?.decl_node;
+        if owner.t != NodeType::InterfaceDecl { return None; }
+        return owner.children.iter()
+            .find(|f| f.t == NodeType::FunctionDecl
+                && f.value == NodeValue::String(name)
+            )
+            .cloned();
+    }
+
+    fn find_method(
+        &mut self, receiver: TypeIdx, name: StringIdx
+    ) -> Option<(PathIdx, AstNode)> {
+        let owner: TypeIdx = match *self.comp.types.get(receiver) {
+            Type::Pointer(_, t) | Type::Reference(_, t) => t,
+            _ => receiver
+        };
+        let owner: PathIdx = match *self.comp.types.get(owner) {
+            Type::Struct(p, _) | Type::Interface(p) => p,
+            _ => return None
+        };
+        let mut method_segs: Vec<StringIdx> = self.comp.paths.get(owner).into();
+        method_segs.push(name);
+        let method: PathIdx = self.comp.paths.insert(&method_segs);
+        return self.find_function(method).map(|decl_node| (method, decl_node));
+    }
+
+    fn check_call(
+        &mut self, n: &AstNode, called: PathIdx, decl_node: &AstNode,
+        receiver: Option<AstNode>
+    ) -> AstNode {
+        let (t_params, params, returned) = function_signature(decl_node);
+        let callee: &AstNode = &n.children[0];
+        let mut t_args: AstNode = match callee.t {
+            NodeType::PathAccess => callee.children[0].clone(),
+            _ => AstNode::new(
+                NodeType::ArgumentList, callee.source, NodeValue::None,
+                Vec::new(), self.comp.types.insert(Type::Unknown)
+            )
+        };
+        let passed_t_args: Vec<TypeIdx> = t_args.children.iter()
+            .map(|t| self.check_type(t))
+            .collect();
+        let mut passed: Vec<(TypeIdx, Source)> = passed_t_args.iter().copied()
+            .zip(t_args.children.iter().map(|t| t.source))
+            .collect();
+        if passed.is_empty() && !t_params.children.is_empty() {
+            passed = self.infer_t_args(
+                n, called, t_params, params, receiver.as_ref()
+            );
+            t_args.children = passed.iter()
+                .map(|(t, source)| AstNode::new(
+                    NodeType::InferredType, *source, NodeValue::None,
+                    Vec::new(), *t
+                ))
+                .collect();
+        }
+        if passed.len() != t_params.children.len() {
+            self.comp.errors.push(Error::dynamic(
+                format!(
+                    "'{}' expects {} template argument{}, but got {}",
+                    called.display(self.comp),
+                    t_params.children.len(),
+                    if t_params.children.len() == 1 { "" } else { "s" },
+                    passed_t_args.len()
+                ),
+                t_args.source
+            ));
+        } else {
+            self.check_constraints(t_params, &passed);
+        }
+        let unknown: TypeIdx = self.comp.types.insert(Type::Unknown);
+        self.templs.push(t_params.children.iter()
+            .enumerate()
+            .map(|(i, a)| match a.value {
+                NodeValue::String(name) => 
+                    (name, passed.get(i).map(|(t, _)| *t).unwrap_or(unknown)),
+                _ => unreachable!("should have a value")
+            })
+            .collect()
+        );
+        // errors in the signature are reported at the declaration
+        let error_count: usize = self.comp.errors.len();
+        let param_types: Vec<TypeIdx> = params.children.iter()
+            .map(|p| self.check_type(p.children.last().expect("has type")))
+            .collect();
+        let return_type: TypeIdx = self.check_type(returned);
+        self.comp.errors.truncate(error_count);
+        self.templs.pop();
+        let mut args: Vec<AstNode> = Vec::new();
+        if let Some(receiver) = receiver {
+            args.push(match param_types.first() {
+                Some(param_type) => self.pass_receiver(receiver, *param_type),
+                None => receiver
+            });
+        }
+        let is_variadic: bool = decl_node.children.iter()
+            .any(|c| c.t == NodeType::IsVariadic);
+        for arg in &n.children[1].children {
+            let param_type: Option<TypeIdx> = param_types.get(args.len())
+                .copied();
+            if param_type.is_none() && is_variadic {
+                args.push(self.check_variadic_arg(arg));
+                continue;
+            }
+            let arg: AstNode = self.check_node(arg, param_type);
+            if let Some(param_type) = param_type {
+                self.expect_type(param_type, &arg);
+            }
+            args.push(arg);
+        }
+        let is_missing: bool = args.len() < param_types.len();
+        if is_missing || (args.len() > param_types.len() && !is_variadic) {
+            self.comp.errors.push(Error::dynamic(
+                format!(
+                    "'{}' expects {}{} argument{}, but got {}",
+                    called.display(self.comp),
+                    if is_variadic { "at least " } else { "" },
+                    param_types.len(),
+                    if param_types.len() == 1 { "" } else { "s" },
+                    args.len()
+                ),
+                n.source
+            ));
+        }
+        let callee: AstNode = AstNode::new(
+            NodeType::PathAccess, callee.source, NodeValue::Path(called),
+            vec!(t_args), unknown
+        );
+        let args: AstNode = AstNode::new(
+            NodeType::ArgumentList, n.children[1].source, NodeValue::None,
+            args, unknown
+        );
+        return AstNode::new(
+            n.t, n.source, n.value, vec!(callee, args), return_type
+        );
+    }
+
+    // template arguments that are not passed are inferred from the arguments
+    fn infer_t_args(
+        &mut self, n: &AstNode, called: PathIdx, t_params: &AstNode,
+        params: &AstNode, receiver: Option<&AstNode>
+    ) -> Vec<(TypeIdx, Source)> {
+        let mut inferred: HashMap<StringIdx, (TypeIdx, Source)> = HashMap::new();
+        let mut params = params.children.iter()
+            .map(|p| p.children.last().expect("should have a type"));
+        if let Some(receiver) = receiver {
+            let param: Option<&AstNode> = params.next();
+            let is_pointer: bool = matches!(
+                self.comp.types.get(receiver.rtype),
+                Type::Pointer(_, _) | Type::Reference(_, _)
+            );
+            // receivers are passed by pointer if they are values
+            let param: Option<&AstNode> = match param {
+                Some(p) if !is_pointer && (p.t == NodeType::PointerType
+                    || p.t == NodeType::ReferenceType) => p.children.last(),
+                p => p
+            };
+            if let Some(param) = param {
+                self.infer_t_arg(
+                    param, receiver.rtype, receiver.source, &mut inferred
+                );
+            }
+        }
+        for (arg, param) in n.children[1].children.iter().zip(params) {
+            // the argument is checked again once the parameter type is known
+            let error_count: usize = self.comp.errors.len();
+            let arg: AstNode = self.check_node(arg, None);
+            self.comp.errors.truncate(error_count);
+            self.infer_t_arg(param, arg.rtype, arg.source, &mut inferred);
+        }
+        let unknown: TypeIdx = self.comp.types.insert(Type::Unknown);
+        let mut t_args: Vec<(TypeIdx, Source)> = Vec::new();
+        for t_param in &t_params.children {
+            let NodeValue::String(name) = t_param.value
+                else { unreachable!("should have a value") };
+            if let Some(t_arg) = inferred.get(&name) {
+                t_args.push(*t_arg);
+                continue;
+            }
+            self.comp.errors.push(Error::dynamic(
+                format!(
+                    concat!(
+                        "the template argument '{}' of '{}' cannot be ",
+                        "inferred from the arguments, so it needs to be specified"
+                    ),
+                    self.comp.strings.get(name), called.display(self.comp)
+                ),
+                n.source
+            ));
+            t_args.push((unknown, n.source));
+        }
+        return t_args;
+    }
+
+    fn infer_t_arg(
+        &mut self, n: &AstNode, t: TypeIdx, source: Source,
+        inferred: &mut HashMap<StringIdx, (TypeIdx, Source)>
+    ) {
+        let unknown: TypeIdx = self.comp.types.insert(Type::Unknown);
+        match (n.t, n.value, self.comp.types.get(t).clone()) {
+            (NodeType::PathAccess, NodeValue::Path(p), _)
+                    if n.children[0].children.is_empty() 
+                    && self.comp.paths.get(p).len() == 1 => {
+                let name: StringIdx = self.comp.paths.get(p)[0];
+                let Some((bound, _)) = inferred.get(&name).copied() else {
+                    inferred.insert(name, (t, source));
+                    return;
+                };
+                // the types of literals are replaced by more specific ones
+                let is_literal: bool = matches!(
+                    self.comp.types.get(bound), Type::Integer | Type::Float
+                );
+                let matched: TypeIdx = self.match_types(bound, t);
+                if is_literal && matched != unknown {
+                    inferred.insert(name, (matched, source));
+                }
+            }
+            (NodeType::PathAccess, NodeValue::Path(p), Type::Struct(s, t_args))
+                    if p == s => {
+                for (t_arg, t) in n.children[0].children.iter().zip(t_args) {
+                    self.infer_t_arg(t_arg, t, source, inferred);
+                }
+            }
+            (NodeType::PointerType, _, Type::Pointer(_, t)) |
+            (NodeType::ReferenceType, _, Type::Reference(_, t)) |
+            (NodeType::SliceType, _, Type::Slice(_, t)) => self.infer_t_arg(
+                n.children.last().expect("should have a type"),
+                t, source, inferred
+            ),
+            (NodeType::NullableType, _, Type::Nullable(t)) => {
+                self.infer_t_arg(&n.children[0], t, source, inferred);
+            }
+            (NodeType::NullableType, _, Type::Pointer(_, _)) => {
+                self.infer_t_arg(&n.children[0], t, source, inferred);
+            }
+            (NodeType::ArrayType, _, Type::Array(_, t)) => {
+                self.infer_t_arg(&n.children[1], t, source, inferred);
+            }
+            (NodeType::TupleType, _, Type::Tuple(elements)) => {
+                for (element, t) in n.children.iter().zip(elements) {
+                    self.infer_t_arg(element, t, source, inferred);
+                }
+            }
+            _ => {}
+        }
     }
 
-    pub fn check_function(&mut self) {
-        // TODO!
+    fn check_variadic_arg(&mut self, n: &AstNode) -> AstNode {
+        // literals without a suffix are passed as 'int' or 'double' in C
+        let error_count: usize = self.comp.errors.len();
+        let mut arg: AstNode = self.check_node(n, None);
+        let literal_type: Option<Type> = match self.comp.types.get(arg.rtype) {
+            Type::Integer => Some(Type::S32),
+            Type::Float => Some(Type::F64),
+            _ => None
+        };
+        if let Some(literal_type) = literal_type {
+            self.comp.errors.truncate(error_count);
+            let literal_type: TypeIdx = self.comp.types.insert(literal_type);
+            arg = self.check_node(n, Some(literal_type));
+        }
+        // the default argument promotions of C
+        let (promoted, promoted_node): (Type, NodeType) = match self.comp.types
+            .get(arg.rtype) {
+            Type::U8 | Type::U16 | Type::S8 | Type::S16 | Type::Boolean =>
+                (Type::S32, NodeType::S32Type),
+            Type::F32 => (Type::F64, NodeType::F64Type),
+            Type::U32 | Type::U64 | Type::Usize | Type::S32 | Type::S64 
+                | Type::F64 | Type::Pointer(_, _) | Type::Reference(_, _)
+                | Type::Nullable(_) | Type::Null | Type::Unknown => return arg,
+            _ => {
+                self.comp.errors.push(Error::dynamic(
+                    format!(
+                        "a value of type '{}' cannot be passed as a variadic argument",
+                        arg.rtype.display(self.comp)
+                    ),
+                    arg.source
+                ));
+                return arg;
+            }
+        };
+        let promoted: TypeIdx = self.comp.types.insert(promoted);
+        let promoted_node: AstNode = AstNode::new(
+            promoted_node, arg.source, NodeValue::None, Vec::new(), promoted
+        );
+        return AstNode::new(
+            NodeType::TypeCast, arg.source, NodeValue::None,
+            vec!(arg, promoted_node), promoted
+        );
+    }
+
+    fn check_variant_literal(&mut self, n: &AstNode, variant: PathIdx) -> AstNode {
+        let mut enum_path: Vec<StringIdx> = self.comp.paths.get(variant).into();
+        enum_path.pop();
+        let enum_path: PathIdx = self.comp.paths.insert(&enum_path);
+        let rtype: TypeIdx = self.comp.types.insert(Type::Enum(enum_path));
+        let payload: Vec<TypeIdx> = match self.variant_payload(variant) {
+            Some(payload) => payload,
+            None => {
+                self.comp.errors.push(Error::dynamic(
+                    format!(
+                        "the variant '{}' does not carry any values",
+                        variant.display(self.comp)
+                    ),
+                    n.source
+                ));
+                Vec::new()
+            }
+        };
+        let mut values: Vec<AstNode> = Vec::new();
+        for value in &n.children[1].children {
+            let value_type: Option<TypeIdx> = payload.get(values.len()).copied();
+            let value: AstNode = self.check_node(value, value_type);
+            if let Some(value_type) = value_type {
+                self.expect_type(value_type, &value);
+            }
+            values.push(value);
+        }
+        if values.len() != payload.len() && !payload.is_empty() {
+            self.comp.errors.push(Error::dynamic(
+                format!(
+                    "'{}' carries {} value{}, but got {}",
+                    variant.display(self.comp),
+                    payload.len(),
+                    if payload.len() == 1 { "" } else { "s" },
+                    values.len()
+                ),
+                n.source
+            ));
+        }
+        let unknown: TypeIdx = self.comp.types.insert(Type::Unknown);
+        let values: AstNode = AstNode::new(
+            NodeType::ArgumentList, n.children[1].source, NodeValue::None,
+            values, unknown
+        );
+        return AstNode::new(
+            n.t, n.source, n.value, vec!(n.children[0].clone(), values), rtype
+        );
+    }
+
+    fn pass_receiver(&mut self, receiver: AstNode, param_type: TypeIdx) -> AstNode {
+        let param: Type = self.comp.types.get(param_type).clone();
+        let passed: AstNode = match (&param, self.comp.types.get(receiver.rtype)) {
+            (
+                Type::Pointer(_, _) | Type::Reference(_, _),
+                Type::Struct(_, _) | Type::Interface(_)
+            ) => {
+                // constants and temporaries only get a constant pointer
+                let is_const: bool = self.immutability(&receiver).is_some();
+                let rtype: TypeIdx = self.comp.types.insert(match param {
+                    Type::Pointer(_, _) => {
+                        Type::Pointer(is_const, receiver.rtype)
+                    }
+                    _ => Type::Reference(is_const, receiver.rtype)
+                });
+                AstNode::new(
+                    NodeType::AddressOf, receiver.source, NodeValue::None,
+                    vec!(receiver), rtype
+                )
+            }
+            (
+                Type::Struct(_, _),
+                Type::Pointer(_, pointee) | Type::Reference(_, pointee)
+            ) => {
+                let rtype: TypeIdx = *pointee;
+                AstNode::new(
+                    NodeType::Deref, receiver.source, NodeValue::None,
+                    vec!(receiver), rtype
+                )
+            }
+            // references and pointers are converted into each other
+            (Type::Pointer(_, _), &Type::Reference(is_const, pointee)) |
+            (Type::Reference(_, _), &Type::Pointer(is_const, pointee)) => {
+                let rtype: TypeIdx = self.comp.types.insert(match param {
+                    Type::Pointer(_, _) => Type::Pointer(is_const, pointee),
+                    _ => Type::Reference(is_const, pointee)
+                });
+                let source: Source = receiver.source;
+                let deref: AstNode = AstNode::new(
+                    NodeType::Deref, source, NodeValue::None,
+                    vec!(receiver), pointee
+                );
+                AstNode::new(
+                    NodeType::AddressOf, source, NodeValue::None,
+                    vec!(deref), rtype
+                )
+            }
+            _ => receiver
+        };
+        self.expect_type(param_type, &passed);
+        return passed;
+    }
+
+    fn expect_type(&mut self, expected: TypeIdx, got: &AstNode) {
+        let unknown: TypeIdx = self.comp.types.insert(Type::Unknown);
+        if expected == unknown || got.rtype == unknown { return; }
+        let matched: TypeIdx = self.match_types(expected, got.rtype);
+        let drops_null: bool = match (
+            self.comp.types.get(expected), self.comp.types.get(matched)
+        ) {
+            (Type::Nullable(_), _) => false,
+            (_, Type::Nullable(_) | Type::Null) => true,
+            _ => false
+        };
+        let pointer = |tc: &Self, t: TypeIdx| match tc.comp.types.get(t) {
+            Type::Nullable(p) => *p,
+            _ => t
+        };
+        let drops_const: bool = matches!(
+            (
+                self.comp.types.get(pointer(self, expected)),
+                self.comp.types.get(pointer(self, got.rtype))
+            ),
+            (Type::Pointer(false, _), Type::Pointer(true, _)) |
+            (Type::Reference(false, _), Type::Reference(true, _)) |
+            (Type::Slice(false, _), Type::Slice(true, _))
+        );
+        if matched != unknown && !drops_null && !drops_const { return; }
+        self.comp.errors.push(Error::dynamic(
+            format!(
+                "expected a value of type '{}', but got a value of type '{}'",
+                expected.display(self.comp), got.rtype.display(self.comp)
+            ),
+            got.source
+        ));
     }
 
     pub fn check_node(&mut self, n: &AstNode, e: Option<TypeIdx>) -> AstNode {
+        let unit: TypeIdx = self.comp.types.insert(Type::Unit);
+        let unknown: TypeIdx = self.comp.types.insert(Type::Unknown);
+        let boolean: TypeIdx = self.comp.types.insert(Type::Boolean);
         match (n.t, n.value) {
+            (NodeType::Block, _) => {
+                let scope: ScopeIdx = self.comp.scopes.insert(Scope::new());
+                self.scopes.push(scope);
+                let narrowed_count: usize = self.narrowed.len();
+                let statements: Vec<AstNode> = n.children.iter()
+                    .map(|s| match s.t {
+                        NodeType::BlockValue => {
+                            let value: AstNode = self.check_node(&s.children[0], e);
+                            AstNode::new(
+                                s.t, s.source, s.value, vec!(value.clone()), 
+                                value.rtype
+                            )
+                        }
+                        _ => self.check_node(s, None)
+                    })
+                    .collect();
+                let statements: Vec<AstNode> = self.lower_defers(statements);
+                self.narrowed.truncate(narrowed_count);
+                self.scopes.pop();
+                // blocks that are always left early may be used as any value
+                let rtype: TypeIdx = match statements.last().map(|s| s.t) {
+                    Some(NodeType::BlockValue) => statements.last()
+                        .expect("checked above").rtype,
+                    Some(NodeType::Return | NodeType::Break | NodeType::Continue) =>
+                        unknown,
+                    _ => unit
+                };
+                return AstNode::new(
+                    n.t, n.source, NodeValue::Scope(scope), statements, rtype
+                );
+            }
+            (NodeType::VariableDecl, NodeValue::String(name)) => {
+                let mut children: Vec<AstNode> = n.children.clone();
+                let type_i: usize = variable_type_index(n);
+                let value_type: TypeIdx = self.check_type(&n.children[type_i]);
+                let mut state: VariableState = VariableState::Uninitialized;
+                if let Some(value) = n.children.get(type_i + 1) {
+                    let value: AstNode = self.check_node(value, Some(value_type));
+                    self.expect_type(value_type, &value);
+                    children[type_i + 1] = value;
+                    state = VariableState::Initialized;
+                }
+                let is_constant: bool = n.children.iter()
+                    .any(|c| c.t == NodeType::IsConstant);
+                let value: Option<Constant> = children.get(type_i + 1)
+                    .filter(|_| is_constant)
+                    .and_then(|v| eval_constant(self.comp, v, &self.scopes).ok());
+                let scope: ScopeIdx = *self.scopes.last()
+                    .expect("should be in a function");
+                self.comp.scopes.get_mut(scope)
+                    .insert(name, n.source, state, value_type, is_constant);
+                if let Some(value) = value {
+                    self.comp.scopes.get_mut(scope).set_value(name, value);
+                }
+                return AstNode::new(n.t, n.source, n.value, children, unit);
+            }
+            (NodeType::TupleDecl, _) => {
+                let value: AstNode = self.check_node(
+                    n.children.last().expect("should have a value"), None
+                );
+                let names: Vec<&AstNode> = n.children.iter()
+                    .filter(|c| c.t == NodeType::Binding 
+                        || c.t == NodeType::Wildcard)
+                    .collect();
+                let elements: Vec<TypeIdx> = match self.comp.types
+                    .get(value.rtype) {
+                    Type::Tuple(elements) => elements.clone(),
+                    Type::Unknown => vec!(unknown; names.len()),
+                    _ => {
+                        self.comp.errors.push(Error::dynamic(
+                            format!(
+                                "a value of type '{}' cannot be destructured",
+                                value.rtype.display(self.comp)
+                            ),
+                            value.source
+                        ));
+                        vec!(unknown; names.len())
+                    }
+                };
+                if elements.len() != names.len() {
+                    self.comp.errors.push(Error::dynamic(
+                        format!(
+                            "'{}' has {} element{}, but {} name{} given",
+                            value.rtype.display(self.comp),
+                            elements.len(),
+                            if elements.len() == 1 { "" } else { "s" },
+                            names.len(),
+                            if names.len() == 1 { " is" } else { "s are" }
+                        ),
+                        n.source
+                    ));
+                }
+                let mut children: Vec<AstNode> = n.children.iter()
+                    .filter(|c| c.t == NodeType::IsConstant)
+                    .cloned()
+                    .collect();
+                let is_constant: bool = !children.is_empty();
+                for (i, name) in names.into_iter().enumerate() {
+                    let name: AstNode = AstNode::new(
+                        name.t, name.source, name.value, Vec::new(),
+                        *elements.get(i).unwrap_or(&unknown)
+                    );
+                    self.declare_bindings(&name, is_constant);
+                    children.push(name);
+                }
+                children.push(value);
+                return AstNode::new(n.t, n.source, n.value, children, unit);
+            }
+            (NodeType::Return, _) => {
+                let return_type: TypeIdx = *self.returns.last()
+                    .expect("should be in a function");
+                let value: AstNode = self.check_node(
+                    &n.children[0], Some(return_type)
+                );
+                self.expect_type(return_type, &value);
+                return AstNode::new(n.t, n.source, n.value, vec!(value), unit);
+            }
+            (NodeType::StaticAssert, _) => return self.check_static_assert(n),
+            (NodeType::Defer, _) => {
+                let deferred: AstNode = self.check_node(&n.children[0], None);
+                self.check_deferred(&deferred, false);
+                return AstNode::new(n.t, n.source, n.value, vec!(deferred), unit);
+            }
+            (NodeType::Continue | NodeType::Break, _) => {
+                return AstNode::new(n.t, n.source, n.value, Vec::new(), unit);
+            }
+            (NodeType::If, _) => {
+                let condition: &AstNode = &n.children[0];
+                let mut scope: Option<ScopeIdx> = None;
+                let condition: AstNode = if condition.t == NodeType::Is {
+                    let value: AstNode = self.check_node(
+                        &condition.children[0], None
+                    );
+                    let variants: Vec<StringIdx> = self.enum_variants(value.rtype);
+                    let pattern_scope: ScopeIdx = self.comp.scopes
+                        .insert(Scope::new());
+                    self.scopes.push(pattern_scope);
+                    scope = Some(pattern_scope);
+                    let (pattern, _) = self.check_pattern(
+                        &condition.children[1], value.rtype, &variants
+                    );
+                    self.declare_bindings(&pattern, false);
+                    AstNode::new(
+                        condition.t, condition.source, 
+                        NodeValue::Scope(pattern_scope), vec!(value, pattern),
+                        boolean
+                    )
+                } else {
+                    let condition: AstNode = self.check_node(
+                        condition, Some(boolean)
+                    );
+                    self.expect_type(boolean, &condition);
+                    condition
+                };
+                let narrowed_count: usize = self.narrowed.len();
+                self.narrowed.extend(self.narrowings(&condition, true));
+                let body: AstNode = self.check_node(&n.children[1], e);
+                self.narrowed.truncate(narrowed_count);
+                if scope.is_some() { self.scopes.pop(); }
+                let mut rtype: TypeIdx = unit;
+                let expected: Option<TypeIdx> = e
+                    .filter(|e| *e != unit && *e != unknown);
+                if let Some(otherwise) = n.children.get(2) {
+                    self.narrowed.extend(self.narrowings(&condition, false));
+                    let otherwise: AstNode = self.check_node(otherwise, e);
+                    self.narrowed.truncate(narrowed_count);
+                    self.narrow_after_if(&condition, &body, Some(&otherwise));
+                    if let Some(expected) = expected {
+                        self.expect_block_type(expected, &body);
+                        self.expect_block_type(expected, &otherwise);
+                    }
+                    rtype = self.match_types(body.rtype, otherwise.rtype);
+                    return AstNode::new(
+                        n.t, n.source, n.value, 
+                        vec!(condition, body, otherwise), rtype
+                    );
+                }
+                self.narrow_after_if(&condition, &body, None);
+                if let Some(expected) = expected {
+                    self.comp.errors.push(Error::dynamic(
+                        format!(
+                            concat!(
+                                "the 'if' needs an 'else' branch to produce ",
+                                "a value of type '{}'"
+                            ),
+                            expected.display(self.comp)
+                        ),
+                        n.source
+                    ));
+                    rtype = unknown;
+                }
+                return AstNode::new(
+                    n.t, n.source, n.value, vec!(condition, body), rtype
+                );
+            }
+            (NodeType::While, _) => {
+                // the loop may be entered again after values were assigned
+                self.forget_narrowings(n);
+                let mut children: Vec<AstNode> = Vec::new();
+                let condition: AstNode = self.check_node(
+                    &n.children[0], Some(boolean)
+                );
+                self.expect_type(boolean, &condition);
+                let narrowed_count: usize = self.narrowed.len();
+                self.narrowed.extend(self.narrowings(&condition, true));
+                children.push(condition);
+                for block in &n.children[1..] {
+                    children.push(self.check_node(block, None));
+                }
+                self.narrowed.truncate(narrowed_count);
+                return AstNode::new(n.t, n.source, n.value, children, unit);
+            }
+            (NodeType::For, NodeValue::String(name)) => {
+                let desugared: AstNode = self.desugar_for(n, name);
+                return self.check_node(&desugared, None);
+            }
+            (NodeType::Match, _) => {
+                let value: AstNode = self.check_node(&n.children[0], None);
+                let value_type: Type = self.comp.types.get(value.rtype).clone();
+                let variants: Vec<StringIdx> = self.enum_variants(value.rtype);
+                let domain: Option<(i128, i128)> = match value_type {
+                    Type::Boolean => Some((0, 1)),
+                    Type::Enum(_) => {
+                        if variants.is_empty() { None }
+                            else { Some((0, variants.len() as i128 - 1)) }
+                    }
+                    Type::Unknown | Type::Integer => None,
+                    ref t if t.is_integer() => integer_range(self.comp, t),
+                    _ => {
+                        self.comp.errors.push(Error::dynamic(
+                            format!(
+                                "a value of type '{}' cannot be matched on",
+                                value.rtype.display(self.comp)
+                            ),
+                            value.source
+                        ));
+                        None
+                    }
+                };
+                let mut is_complete: bool = value_type == Type::Unknown
+                    || (domain.is_none() && value.rtype != unknown
+                        && !value_type.is_integer());
+                let mut covered: Vec<(i128, i128)> = Vec::new();
+                let mut has_wildcard: bool = false;
+                let mut children: Vec<AstNode> = vec!(value.clone());
+                for arm in &n.children[1..] {
+                    let (body, patterns) = arm.children.split_last()
+                        .expect("should have a body");
+                    let scope: ScopeIdx = self.comp.scopes.insert(Scope::new());
+                    self.scopes.push(scope);
+                    let mut arm_children: Vec<AstNode> = Vec::new();
+                    for pattern in patterns {
+                        let (checked, values) = self.check_pattern(
+                            pattern, value.rtype, &variants
+                        );
+                        let is_unreachable: bool = has_wildcard || match values {
+                            Some((start, end)) => 
+                                first_uncovered(&covered, start, end).is_none(),
+                            None => pattern.t == NodeType::Wildcard 
+                                && domain.map(|(start, end)|
+                                    first_uncovered(&covered, start, end)
+                                        .is_none()
+                                ).unwrap_or(false)
+                        };
+                        if is_unreachable {
+                            self.comp.errors.push(Error::fixed(
+                                concat!(
+                                    "the pattern is unreachable, as all of its ",
+                                    "values are matched by earlier patterns"
+                                ),
+                                pattern.source
+                            ));
+                        }
+                        match values {
+                            Some(values) => covered.push(values),
+                            None if pattern.t == NodeType::Wildcard => {
+                                has_wildcard = true;
+                            }
+                            // invalid patterns are reported already
+                            None => is_complete = true
+                        }
+                        self.declare_bindings(&checked, false);
+                        arm_children.push(checked);
+                    }
+                    let binds: bool = patterns.iter().any(|p| p.children.iter()
+                        .any(|c| c.t == NodeType::Binding)
+                    );
+                    if binds && patterns.len() > 1 {
+                        self.comp.errors.push(Error::fixed(
+                            concat!(
+                                "patterns that bind values cannot be combined ",
+                                "with other patterns"
+                            ),
+                            arm.source
+                        ));
+                    }
+                    arm_children.push(self.check_node(body, None));
+                    self.scopes.pop();
+                    children.push(AstNode::new(
+                        arm.t, arm.source, NodeValue::Scope(scope), 
+                        arm_children, unit
+                    ));
+                }
+                self.forget_narrowings(n);
+                if is_complete || has_wildcard {
+                    return AstNode::new(n.t, n.source, n.value, children, unit);
+                }
+                let missing: Option<String> = match (value_type, domain) {
+                    (Type::Enum(p), Some((start, end))) => {
+                        let missing: Vec<String> = (start..=end)
+                            .filter(|v| first_uncovered(&covered, *v, *v).is_some())
+                            .map(|v| format!(
+                                "'{}::{}'",
+                                p.display(self.comp),
+                                self.comp.strings.get(variants[v as usize])
+                            ))
+                            .collect();
+                        if missing.is_empty() { None } else {
+                            Some(format!(
+                                "the variant{} {}", 
+                                if missing.len() == 1 { "" } else { "s" },
+                                missing.join(", ")
+                            ))
+                        }
+                    }
+                    (Type::Boolean, Some((start, end))) => 
+                        first_uncovered(&covered, start, end)
+                            .map(|v| format!("the value {}", v == 1)),
+                    (_, Some((start, end))) => 
+                        first_uncovered(&covered, start, end)
+                            .map(|v| format!("the value {}", v)),
+                    (_, None) => Some(format!(
+                        "all values of type '{}'", 
+                        value.rtype.display(self.comp)
+                    ))
+                };
+                if let Some(missing) = missing {
+                    self.comp.errors.push(Error::dynamic(
+                        format!(
+                            concat!(
+                                "the match does not handle {} ",
+                                "(add the missing patterns or a '_' arm)"
+                            ),
+                            missing
+                        ),
+                        n.source
+                    ));
+                }
+                return AstNode::new(n.t, n.source, n.value, children, unit);
+            }
+            (NodeType::Loop, _) => {
+                self.forget_narrowings(n);
+                let body: AstNode = self.check_node(&n.children[0], None);
+                return AstNode::new(n.t, n.source, n.value, vec!(body), unit);
+            }
+            (NodeType::Assign | NodeType::AssignAdd | NodeType::AssignSubtract
+                    | NodeType::AssignMultiply | NodeType::AssignDivide
+                    | NodeType::AssignRemainder, _) => {
+                let mut target: AstNode = self.check_node(&n.children[0], None);
+                self.check_assignable(&target);
+                let local: Option<(StringIdx, Variable)> = self
+                    .local_variable(&target);
+                if let Some((_, variable)) = local {
+                    target.rtype = variable.value_type;
+                }
+                let value: AstNode = self.check_node(
+                    &n.children[1], Some(target.rtype)
+                );
+                self.expect_type(target.rtype, &value);
+                if let Some((name, variable)) = local {
+                    let is_pointer: bool = match self.comp.types.get(value.rtype) {
+                        Type::Pointer(_, _) => n.t == NodeType::Assign,
+                        _ => false
+                    };
+                    let narrowed: TypeIdx = match *self.comp.types
+                        .get(variable.value_type) {
+                        Type::Nullable(pointer) if is_pointer => pointer,
+                        _ => variable.value_type
+                    };
+                    self.narrowed.push((name, variable.source, narrowed));
+                }
+                return AstNode::new(
+                    n.t, n.source, n.value, vec!(target, value), unit
+                );
+            }
+            (NodeType::AssignBitwiseAnd | NodeType::AssignBitwiseOr
+                    | NodeType::AssignBitwiseXor, _) => {
+                let target: AstNode = self.check_node(&n.children[0], None);
+                self.check_assignable(&target);
+                let value: AstNode = self.check_node(
+                    &n.children[1], Some(target.rtype)
+                );
+                self.expect_type(target.rtype, &value);
+                let target_type: &Type = self.comp.types.get(target.rtype);
+                if !target_type.is_integer() && *target_type != Type::Unknown {
+                    self.report_operands(n, &target, &value);
+                }
+                return AstNode::new(
+                    n.t, n.source, n.value, vec!(target, value), unit
+                );
+            }
+            (NodeType::AssignShiftLeft | NodeType::AssignShiftRight, _) => {
+                let target: AstNode = self.check_node(&n.children[0], None);
+                self.check_assignable(&target);
+                let amount: AstNode = self.check_node(&n.children[1], None);
+                self.check_shift(&target, &amount);
+                return AstNode::new(
+                    n.t, n.source, n.value, vec!(target, amount), unit
+                );
+            }
+            (NodeType::PathAccess, NodeValue::Path(p)) => {
+                let mut rtype: TypeIdx = unknown;
+                if let Some(variable) = self.find_variable(p) {
+                    let [name] = self.comp.paths.get(p) else { unreachable!() };
+                    rtype = self.narrowed_type(*name, &variable);
+                } else {
+                    let decl_node: Option<AstNode> = self.comp.symbols
+                        .symbols().get(&p).map(|s| s.decl_node.clone());
+                    match decl_node.as_ref().map(|d| d.t) {
+                        Some(NodeType::VariableDecl) => {
+                            let decl_node: AstNode = decl_node
+                                .expect("was found");
+                            // errors in the type are reported at the declaration
+                            let error_count: usize = self.comp.errors.len();
+                            rtype = self.check_type(
+                                &decl_node.children[variable_type_index(&decl_node)]
+                            );
+                            self.comp.errors.truncate(error_count);
+                        }
+                        Some(NodeType::EnumVariant) => {
+                            let mut segments: Vec<StringIdx> = self.comp.paths
+                                .get(p).into();
+                            segments.pop();
+                            let enum_path: PathIdx = self.comp.paths
+                                .insert(&segments);
+                            rtype = self.comp.types.insert(Type::Enum(enum_path));
+                            if self.variant_payload(p).is_some() {
+                                self.comp.errors.push(Error::dynamic(
+                                    format!(
+                                        concat!(
+                                            "the variant '{}' carries values, ",
+                                            "which need to be specified"
+                                        ),
+                                        p.display(self.comp)
+                                    ),
+                                    n.source
+                                ));
+                            }
+                        }
+                        // TODO: function pointers
+                        Some(NodeType::FunctionDecl) => {}
+                        Some(_) => {
+                            self.comp.errors.push(Error::dynamic(
+                                format!(
+                                    "the symbol '{}' cannot be used as a value",
+                                    p.display(self.comp)
+                                ),
+                                n.source
+                            ));
+                        }
+                        None => {
+                            self.comp.errors.push(Error::dynamic(
+                                format!(
+                                    "the symbol '{}' does not exist",
+                                    p.display(self.comp)
+                                ),
+                                n.source
+                            ));
+                        }
+                    }
+                }
+                return AstNode::new(
+                    n.t, n.source, n.value, n.children.clone(), rtype
+                );
+            }
+            (NodeType::IntegerLiteral, _) => {
+                return self.check_integer_literal(n, e, false);
+            }
+            (NodeType::FloatLiteral, NodeValue::String(v)) => {
+                let rtype: TypeIdx = match n.children.first() {
+                    Some(suffix) => self.check_type(suffix),
+                    None => e
+                        .filter(|e| self.comp.types.get(*e).is_float())
+                        .unwrap_or_else(|| self.comp.types.insert(Type::Float))
+                };
+                let value: f64 = self.comp.strings.get(v).parse()
+                    .unwrap_or(f64::INFINITY);
+                let fits: bool = match self.comp.types.get(rtype) {
+                    Type::F32 => (value as f32).is_finite(),
+                    _ => value.is_finite()
+                };
+                if !fits {
+                    self.comp.errors.push(Error::dynamic(
+                        format!(
+                            "the value {} does not fit into the type '{}'",
+                            self.comp.strings.get(v), rtype.display(self.comp)
+                        ),
+                        n.source
+                    ));
+                }
+                return AstNode::new(
+                    n.t, n.source, n.value, n.children.clone(), rtype
+                );
+            }
+            (NodeType::CharLiteral, NodeValue::String(v)) => {
+                let value: char = self.comp.strings.get(v).chars().next()
+                    .unwrap_or('\0');
+                let rtype: TypeIdx = e
+                    .filter(|e| integer_range(
+                        self.comp, self.comp.types.get(*e)
+                    ).is_some())
+                    .unwrap_or_else(|| self.comp.types.insert(Type::U32));
+                let (min, max) = integer_range(
+                    self.comp, self.comp.types.get(rtype)
+                ).expect("should be an integer type");
+                if (value as i128) < min || (value as i128) > max {
+                    self.comp.errors.push(Error::dynamic(
+                        format!(
+                            "the character '{}' ({}) does not fit into the type '{}'",
+                            value.escape_default(), value as u32,
+                            rtype.display(self.comp)
+                        ),
+                        n.source
+                    ));
+                }
+                return AstNode::new(n.t, n.source, n.value, Vec::new(), rtype);
+            }
+            // TODO: string type
+            (NodeType::StringLiteral, _) => {
+                return AstNode::new(n.t, n.source, n.value, Vec::new(), unknown);
+            }
+            (NodeType::CStringLiteral, _) => {
+                let u8_type: TypeIdx = self.comp.types.insert(Type::U8);
+                let rtype: TypeIdx = self.comp.types.insert(
+                    Type::Pointer(true, u8_type)
+                );
+                return AstNode::new(n.t, n.source, n.value, Vec::new(), rtype);
+            }
+            (NodeType::NullLiteral, _) => {
+                let rtype: TypeIdx = match e.map(|e| self.comp.types.get(e)) {
+                    Some(Type::Nullable(_)) => e.expect("was matched"),
+                    _ => self.comp.types.insert(Type::Null)
+                };
+                return AstNode::new(n.t, n.source, n.value, Vec::new(), rtype);
+            }
+            (NodeType::UnitLiteral, _) => {
+                return AstNode::new(n.t, n.source, n.value, Vec::new(), unit);
+            }
+            (NodeType::BooleanLiteral, _) => {
+                return AstNode::new(n.t, n.source, n.value, Vec::new(), boolean);
+            }
+            (NodeType::TypeCast, _) => {
+                let value: AstNode = self.check_node(&n.children[0], None);
+                let rtype: TypeIdx = self.check_type(&n.children[1]);
+                return AstNode::new(
+                    n.t, n.source, n.value, 
+                    vec!(value, n.children[1].clone()), rtype
+                );
+            }
+            (NodeType::SizeOf, _) => {
+                // the size is kept for the evaluation of constants
+                let value: NodeValue = match type_layout(self, &n.children[0]) {
+                    Some(layout) => NodeValue::String(
+                        self.comp.strings.insert(&layout.size.to_string())
+                    ),
+                    None => NodeValue::None
+                };
+                let rtype: TypeIdx = self.comp.types.insert(Type::Usize);
+                return AstNode::new(
+                    n.t, n.source, value, n.children.clone(), rtype
+                );
+            }
+            (NodeType::AddressOf, _) => {
+                let (is_reference, pointee) = match e
+                    .map(|e| self.comp.types.get(e)) {
+                    Some(Type::Pointer(_, t)) => (false, Some(*t)),
+                    Some(Type::Reference(_, t)) => (true, Some(*t)),
+                    _ => (false, None)
+                };
+                let mut value: AstNode = self.check_node(&n.children[0], pointee);
+                // the pointer may be used to assign null to the variable
+                if let Some((name, variable)) = self.local_variable(&value) {
+                    value.rtype = variable.value_type;
+                    self.narrowed.push(
+                        (name, variable.source, variable.value_type)
+                    );
+                }
+                let is_const: bool = self.immutability(&value).is_some();
+                let rtype: TypeIdx = self.comp.types.insert(
+                    if is_reference { Type::Reference(is_const, value.rtype) }
+                    else { Type::Pointer(is_const, value.rtype) }
+                );
+                return AstNode::new(n.t, n.source, n.value, vec!(value), rtype);
+            }
+            (NodeType::Deref, _) => {
+                let value: AstNode = self.check_node(&n.children[0], None);
+                let rtype: TypeIdx = match *self.comp.types.get(value.rtype) {
+                    Type::Pointer(_, t) | Type::Reference(_, t) => t,
+                    Type::Unknown => unknown,
+                    Type::Nullable(_) => {
+                        self.report_nullable(&value);
+                        unknown
+                    }
+                    _ => {
+                        self.comp.errors.push(Error::dynamic(
+                            format!(
+                                "a value of type '{}' cannot be dereferenced",
+                                value.rtype.display(self.comp)
+                            ),
+                            value.source
+                        ));
+                        unknown
+                    }
+                };
+                return AstNode::new(n.t, n.source, n.value, vec!(value), rtype);
+            }
+            (NodeType::Negate, _) => {
+                let value: AstNode = match n.children[0].t {
+                    NodeType::IntegerLiteral => self.check_integer_literal(
+                        &n.children[0], e, true
+                    ),
+                    _ => self.check_node(&n.children[0], e)
+                };
+                if let Some(call) = self.check_operator(n, &value) { return call; }
+                let value_type: &Type = self.comp.types.get(value.rtype);
+                if !value_type.is_integer() && !value_type.is_float() 
+                        && *value_type != Type::Unknown {
+                    self.comp.errors.push(Error::dynamic(
+                        format!(
+                            "a value of type '{}' cannot be negated",
+                            value.rtype.display(self.comp)
+                        ),
+                        value.source
+                    ));
+                }
+                let rtype: TypeIdx = value.rtype;
+                return AstNode::new(n.t, n.source, n.value, vec!(value), rtype);
+            }
+            (NodeType::BitwiseNot, _) => {
+                let value: AstNode = self.check_node(&n.children[0], e);
+                if !self.is_integer(value.rtype) && value.rtype != unknown {
+                    self.comp.errors.push(Error::dynamic(
+                        format!(
+                            "the bits of a value of type '{}' cannot be inverted",
+                            value.rtype.display(self.comp)
+                        ),
+                        value.source
+                    ));
+                }
+                let rtype: TypeIdx = value.rtype;
+                return AstNode::new(n.t, n.source, n.value, vec!(value), rtype);
+            }
+            (NodeType::BitwiseAnd | NodeType::BitwiseOr 
+                    | NodeType::BitwiseXor, _) => {
+                let left: AstNode = self.check_node(&n.children[0], e);
+                let right: AstNode = self.check_node(&n.children[1], e);
+                let rtype: TypeIdx = self.match_types(left.rtype, right.rtype);
+                let is_integer: bool = self.is_integer(rtype);
+                if (rtype != unknown && !is_integer) || (rtype == unknown
+                        && left.rtype != unknown && right.rtype != unknown) {
+                    self.report_operands(n, &left, &right);
+                }
+                return AstNode::new(
+                    n.t, n.source, n.value, vec!(left, right), rtype
+                );
+            }
+            (NodeType::ShiftLeft | NodeType::ShiftRight, _) => {
+                let value: AstNode = self.check_node(&n.children[0], e);
+                let amount: AstNode = self.check_node(&n.children[1], None);
+                self.check_shift(&value, &amount);
+                let rtype: TypeIdx = value.rtype;
+                return AstNode::new(
+                    n.t, n.source, n.value, vec!(value, amount), rtype
+                );
+            }
+            (NodeType::LogicalNot, _) => {
+                let value: AstNode = self.check_node(&n.children[0], Some(boolean));
+                self.expect_type(boolean, &value);
+                return AstNode::new(n.t, n.source, n.value, vec!(value), boolean);
+            }
+            (NodeType::LogicalAnd | NodeType::LogicalOr, _) => {
+                let left: AstNode = self.check_node(&n.children[0], Some(boolean));
+                self.expect_type(boolean, &left);
+                // the right side is only evaluated depending on the left side
+                let narrowed_count: usize = self.narrowed.len();
+                self.narrowed.extend(
+                    self.narrowings(&left, n.t == NodeType::LogicalAnd)
+                );
+                let right: AstNode = self.check_node(&n.children[1], Some(boolean));
+                self.narrowed.truncate(narrowed_count);
+                self.expect_type(boolean, &right);
+                return AstNode::new(
+                    n.t, n.source, n.value, vec!(left, right), boolean
+                );
+            }
+            (NodeType::LessThan | NodeType::GreaterThan
+                    | NodeType::LessThanEqual | NodeType::GreaterThanEqual
+                    | NodeType::Equal | NodeType::NotEqual, _) => {
+                let left: AstNode = self.check_node(&n.children[0], None);
+                if let Some(call) = self.check_operator(n, &left) { return call; }
+                let right: AstNode = self.check_node(
+                    &n.children[1], Some(left.rtype)
+                );
+                let compared: TypeIdx = self.match_types(left.rtype, right.rtype);
+                let is_ordered: bool = match n.t {
+                    NodeType::Equal | NodeType::NotEqual => 
+                        match *self.comp.types.get(compared) {
+                            Type::Enum(p) => !self.carries_values(p),
+                            _ => true
+                        },
+                    _ => {
+                        let t: &Type = self.comp.types.get(compared);
+                        t.is_integer() || t.is_float() || *t == Type::Unknown
+                    }
+                };
+                if (compared == unknown && left.rtype != unknown 
+                        && right.rtype != unknown) || !is_ordered {
+                    self.report_operands(n, &left, &right);
+                }
+                let non_null: Option<&AstNode> = match (left.t, right.t) {
+                    (_, NodeType::NullLiteral) => Some(&left),
+                    (NodeType::NullLiteral, _) => Some(&right),
+                    _ => None
+                };
+                let non_null: Option<&AstNode> = non_null.filter(|v| matches!(
+                    self.comp.types.get(v.rtype),
+                    Type::Pointer(_, _) | Type::Reference(_, _)
+                ));
+                if let Some(value) = non_null {
+                    self.comp.errors.push(Error::dynamic(
+                        format!(
+                            concat!(
+                                "the comparison is always {}, since values ",
+                                "of type '{}' are never null"
+                            ),
+                            n.t == NodeType::NotEqual,
+                            value.rtype.display(self.comp)
+                        ),
+                        n.source
+                    ));
+                }
+                return AstNode::new(
+                    n.t, n.source, n.value, vec!(left, right), boolean
+                );
+            }
             (NodeType::Add | NodeType::Subtract | NodeType::Multiply
                     | NodeType::Divide | NodeType::Remainder, _) => {
                 let left: AstNode = self.check_node(&n.children[0], e);
+                if let Some(call) = self.check_operator(n, &left) { return call; }
                 let right: AstNode = self.check_node(&n.children[1], e);
                 let rtype: TypeIdx = self.match_types(left.rtype, right.rtype);
+                let is_number: bool = {
+                    let t: &Type = self.comp.types.get(rtype);
+                    t.is_integer() || t.is_float()
+                };
+                if (rtype != unknown && !is_number) || (rtype == unknown
+                        && left.rtype != unknown && right.rtype != unknown) {
+                    self.report_operands(n, &left, &right);
+                }
                 return AstNode::new(
                     n.t, n.source, n.value, vec!(left, right), rtype
                 );
             }
+            (NodeType::ArrayLiteral, _) => {
+                let mut element_type: Option<TypeIdx> = e
+                    .and_then(|e| match self.comp.types.get(e) {
+                        Type::Array(_, t) => Some(*t),
+                        _ => None
+                    });
+                let mut values: Vec<AstNode> = Vec::new();
+                for value in &n.children {
+                    let value: AstNode = self.check_node(value, element_type);
+                    element_type = Some(match element_type {
+                        Some(t) => self.match_types(t, value.rtype),
+                        None => value.rtype
+                    });
+                    values.push(value);
+                }
+                let element_type: TypeIdx = element_type
+                    .unwrap_or_else(|| self.comp.types.insert(Type::Unknown));
+                let rtype: TypeIdx = self.comp.types.insert(
+                    Type::Array(values.len() as u64, element_type)
+                );
+                return AstNode::new(n.t, n.source, n.value, values, rtype);
+            }
+            (NodeType::Index, _) => {
+                let indexed: AstNode = self.check_node(&n.children[0], None);
+                let index_type: TypeIdx = self.comp.types.insert(Type::Usize);
+                let index: AstNode = self.check_node(
+                    &n.children[1], Some(index_type)
+                );
+                self.expect_type(index_type, &index);
+                let rtype: TypeIdx = match *self.comp.types.get(indexed.rtype) {
+                    Type::Array(length, element_type) => {
+                        let constant_index: Option<i128> = match eval_constant(
+                            self.comp, &index, &self.scopes
+                        ) {
+                            Ok(Constant::Integer(i)) => Some(i),
+                            _ => None
+                        };
+                        let out_of_bounds: Option<i128> = constant_index
+                            .filter(|i| *i >= length as i128);
+                        if let Some(i) = out_of_bounds {
+                            self.comp.errors.push(Error::dynamic(
+                                format!(
+                                    concat!(
+                                        "the index {} is out of bounds for ",
+                                        "an array of length {}"
+                                    ),
+                                    i, length
+                                ),
+                                index.source
+                            ));
+                        }
+                        element_type
+                    }
+                    Type::Slice(_, element_type) => element_type,
+                    Type::Unknown => indexed.rtype,
+                    _ => {
+                        self.comp.errors.push(Error::dynamic(
+                            format!(
+                                "a value of type '{}' cannot be indexed",
+                                indexed.rtype.display(self.comp)
+                            ),
+                            indexed.source
+                        ));
+                        self.comp.types.insert(Type::Unknown)
+                    }
+                };
+                return AstNode::new(
+                    n.t, n.source, n.value, vec!(indexed, index), rtype
+                );
+            }
+            (NodeType::Subslice, _) => {
+                let sliced: AstNode = self.check_node(&n.children[0], None);
+                let index_type: TypeIdx = self.comp.types.insert(Type::Usize);
+                let bounds: Vec<AstNode> = n.children[1..].iter()
+                    .map(|b| self.check_node(b, Some(index_type)))
+                    .collect();
+                for bound in &bounds {
+                    self.expect_type(index_type, bound);
+                }
+                let constant_bounds: Vec<Option<i128>> = bounds.iter()
+                    .map(|b| match eval_constant(self.comp, b, &self.scopes) {
+                        Ok(Constant::Integer(i)) => Some(i),
+                        _ => None
+                    })
+                    .collect();
+                let rtype: TypeIdx = match *self.comp.types.get(sliced.rtype) {
+                    Type::Array(length, element_type) => {
+                        let out_of_bounds = bounds.iter()
+                            .zip(constant_bounds.iter())
+                            .find(|(_, b)| {
+                                b.is_some_and(|b| b > length as i128)
+                            });
+                        if let Some((bound, value)) = out_of_bounds {
+                            self.comp.errors.push(Error::dynamic(
+                                format!(
+                                    concat!(
+                                        "the index {} is out of bounds for ",
+                                        "an array of length {}"
+                                    ),
+                                    value.expect("was found"), length
+                                ),
+                                bound.source
+                            ));
+                        }
+                        let is_const: bool = self.immutability(&sliced)
+                            .is_some();
+                        self.comp.types.insert(
+                            Type::Slice(is_const, element_type)
+                        )
+                    }
+                    Type::Slice(_, _) => sliced.rtype,
+                    Type::Pointer(is_const, element_type) => {
+                        if bounds.len() < 2 {
+                            self.comp.errors.push(Error::fixed(
+                                concat!(
+                                    "slicing a pointer requires an end ",
+                                    "index, since its length is unknown"
+                                ),
+                                n.source
+                            ));
+                        }
+                        self.comp.types.insert(
+                            Type::Slice(is_const, element_type)
+                        )
+                    }
+                    Type::Unknown => sliced.rtype,
+                    _ => {
+                        self.comp.errors.push(Error::dynamic(
+                            format!(
+                                "a value of type '{}' cannot be sliced",
+                                sliced.rtype.display(self.comp)
+                            ),
+                            sliced.source
+                        ));
+                        self.comp.types.insert(Type::Unknown)
+                    }
+                };
+                if let [Some(start), Some(end)] = constant_bounds[..] {
+                    if start > end {
+                        self.comp.errors.push(Error::dynamic(
+                            format!(
+                                concat!(
+                                    "the start index {} is greater than ",
+                                    "the end index {}"
+                                ),
+                                start, end
+                            ),
+                            n.source
+                        ));
+                    }
+                }
+                let mut children: Vec<AstNode> = vec!(sliced);
+                children.extend(bounds);
+                return AstNode::new(n.t, n.source, n.value, children, rtype);
+            }
+            (NodeType::MemberAccess, NodeValue::String(member)) => {
+                let accessed: AstNode = self.check_node(&n.children[0], None);
+                let is_len: bool = self.comp.strings.get(member) == "len";
+                let rtype: TypeIdx = match self.comp.types.get(accessed.rtype) {
+                    Type::Array(_, _) | Type::Slice(_, _) if is_len => {
+                        self.comp.types.insert(Type::Usize)
+                    }
+                    Type::Struct(p, t_args) => {
+                        let (p, t_args): (PathIdx, Vec<TypeIdx>)
+                            = (*p, t_args.clone());
+                        self.check_field(p, &t_args, member, n.source)
+                    }
+                    Type::Tuple(_) => {
+                        self.check_element(accessed.rtype, member, n.source)
+                    }
+                    Type::Pointer(_, t) | Type::Reference(_, t) => {
+                        let t: TypeIdx = *t;
+                        match self.comp.types.get(t).clone() {
+                            Type::Struct(p, t_args) => {
+                                self.check_field(p, &t_args, member, n.source)
+                            }
+                            Type::Tuple(_) => {
+                                self.check_element(t, member, n.source)
+                            }
+                            _ => self.report_missing_field(t, member, n.source)
+                        }
+                    }
+                    Type::Nullable(_) => {
+                        self.report_nullable(&accessed);
+                        self.comp.types.insert(Type::Unknown)
+                    }
+                    _ => self.report_missing_field(
+                        accessed.rtype, member, n.source
+                    )
+                };
+                return AstNode::new(
+                    n.t, n.source, n.value, vec!(accessed), rtype
+                );
+            }
+            (NodeType::TupleLiteral, _) => {
+                let expected: Vec<TypeIdx> = match e
+                    .map(|e| self.comp.types.get(e)) {
+                    Some(Type::Tuple(elements)) => elements.clone(),
+                    _ => Vec::new()
+                };
+                let values: Vec<AstNode> = n.children.iter().enumerate()
+                    .map(|(i, v)| self.check_node(v, expected.get(i).copied()))
+                    .collect();
+                let rtype: TypeIdx = self.comp.types.insert(Type::Tuple(
+                    values.iter().map(|v| v.rtype).collect()
+                ));
+                return AstNode::new(n.t, n.source, n.value, values, rtype);
+            }
+            (NodeType::StructLiteral, _) => {
+                let struct_type: AstNode = n.children[0].clone();
+                let is_struct: bool = match struct_type.value {
+                    NodeValue::Path(p) => self.comp.symbols.symbols().get(&p)
+                        .map(|s| s.decl_node.t == NodeType::StructDecl)
+                        .unwrap_or(false),
+                    _ => false
+                };
+                let unknown: TypeIdx = self.comp.types.insert(Type::Unknown);
+                let rtype: TypeIdx = if is_struct {
+                    self.check_type(&struct_type)
+                } else {
+                    self.comp.errors.push(Error::fixed(
+                        "only structs can be created using a struct literal",
+                        struct_type.source
+                    ));
+                    unknown
+                };
+                let fields: Option<(PathIdx, Vec<(StringIdx, TypeIdx)>)>
+                    = match self.comp.types.get(rtype).clone() {
+                    Type::Struct(path, t_args) => {
+                        Some((path, self.field_types(path, &t_args)))
+                    }
+                    _ => None
+                };
+                let mut children: Vec<AstNode> = vec!(struct_type.clone());
+                for field in &n.children[1..] {
+                    let NodeValue::String(name) = field.value
+                        else { unreachable!("node must be valid") };
+                    let field_type: TypeIdx = fields.as_ref()
+                        .and_then(|(_, f)| f.iter().find(|(f, _)| *f == name))
+                        .map(|(_, t)| *t)
+                        .unwrap_or(unknown);
+                    let value: AstNode = self.check_node(
+                        &field.children[0], Some(field_type)
+                    );
+                    self.expect_type(field_type, &value);
+                    children.push(AstNode::new(
+                        field.t, field.source, field.value,
+                        vec!(value), field_type
+                    ));
+                }
+                let Some((path, fields)) = fields else {
+                    return AstNode::new(n.t, n.source, n.value, children, rtype);
+                };
+                let mut specified: HashSet<StringIdx> = HashSet::new();
+                for field in &children[1..] {
+                    let NodeValue::String(name) = field.value
+                        else { unreachable!("node must be valid") };
+                    if !fields.iter().any(|(f, _)| *f == name) {
+                        self.comp.errors.push(Error::dynamic(
+                            format!(
+                                "the struct '{}' has no field '{}'",
+                                path.display(self.comp),
+                                self.comp.strings.get(name)
+                            ),
+                            field.source
+                        ));
+                    } else if !specified.insert(name) {
+                        self.comp.errors.push(Error::dynamic(
+                            format!(
+                                "the field '{}' is specified more than once",
+                                self.comp.strings.get(name)
+                            ),
+                            field.source
+                        ));
+                    }
+                }
+                let missing: Vec<String> = fields.iter()
+                    .filter(|(f, _)| !specified.contains(f))
+                    .map(|(f, _)| format!("'{}'", self.comp.strings.get(*f)))
+                    .collect();
+                if !missing.is_empty() {
+                    self.comp.errors.push(Error::dynamic(
+                        format!(
+                            "no value is specified for the field{} {} of '{}'",
+                            if missing.len() == 1 { "" } else { "s" },
+                            missing.join(", "),
+                            path.display(self.comp)
+                        ),
+                        n.source
+                    ));
+                }
+                return AstNode::new(n.t, n.source, n.value, children, rtype);
+            }
             (NodeType::Call, _) => {
-                match (n.children[0].t, n.children[0].value) {
-                    (NodeType::PathAccess, NodeValue::Path(called)) => {
-                        
-                    } 
+                let callee: &AstNode = &n.children[0];
+                let mut checked_callee: Option<AstNode> = None;
+                match (callee.t, callee.value) {
+                    (NodeType::PathAccess, NodeValue::Path(called))
+                            if self.find_variable(called).is_none() => {
+                        if let Some(decl_node) = self.find_function(called) {
+                            return self.check_call(n, called, &decl_node, None);
+                        }
+                        let is_variant: bool = self.comp.symbols.symbols()
+                            .get(&called)
+                            .map(|s| s.decl_node.t == NodeType::EnumVariant)
+                            .unwrap_or(false);
+                        if is_variant {
+                            return self.check_variant_literal(n, called);
+                        }
+                    }
+                    (NodeType::MemberAccess, NodeValue::String(name)) => {
+                        let receiver: AstNode = self.check_node(
+                            &callee.children[0], None
+                        );
+                        if let Some((called, decl_node)) = self
+                            .find_method(receiver.rtype, name) {
+                            return self.check_call(
+                                n, called, &decl_node, Some(receiver)
+                            );
+                        }
+                        let owner: TypeIdx = match *self.comp.types
+                            .get(receiver.rtype) {
+                            Type::Pointer(_, t) | Type::Reference(_, t) => t,
+                            _ => receiver.rtype
+                        };
+                        let is_field: bool = match *self.comp.types.get(owner) {
+                            Type::Struct(p, _) => self.comp.symbols.symbols()
+                                .get(&p).expect("should exist")
+                                .decl_node.children.iter()
+                                .filter(|c| c.t == NodeType::ArgumentList)
+                                .nth(1).expect("should have fields")
+                                .children.iter()
+                                .any(|f| f.value == NodeValue::String(name)),
+                            _ => false
+                        };
+                        let mut rtype: TypeIdx = unknown;
+                        if let (true, Type::Struct(p, t_args)) 
+                                = (is_field, self.comp.types.get(owner).clone()) {
+                            rtype = self.check_field(
+                                p, &t_args, name, callee.source
+                            );
+                        } else if receiver.rtype != unknown {
+                            self.comp.errors.push(Error::dynamic(
+                                format!(
+                                    "the type '{}' has no function '{}'",
+                                    receiver.rtype.display(self.comp),
+                                    self.comp.strings.get(name)
+                                ),
+                                callee.source
+                            ));
+                        }
+                        checked_callee = Some(AstNode::new(
+                            callee.t, callee.source, callee.value,
+                            vec!(receiver), rtype
+                        ));
+                    }
                     _ => {}
                 }
-                todo!("function pointer calls")
+                // TODO: function pointer calls
+                let callee: AstNode = checked_callee
+                    .unwrap_or_else(|| self.check_node(callee, None));
+                let args: Vec<AstNode> = n.children[1].children.iter()
+                    .map(|a| self.check_node(a, None))
+                    .collect();
+                let args: AstNode = AstNode::new(
+                    NodeType::ArgumentList, n.children[1].source,
+                    NodeValue::None, args, unknown
+                );
+                return AstNode::new(
+                    n.t, n.source, n.value, vec!(callee, args), unknown
+                );
             }
             _ => unreachable!("node must be valid")
         }
     }
 
-    pub fn match_types(&self, left: TypeIdx, right: TypeIdx) -> TypeIdx {
-        // TODO: actual implementation
-        //       if any type is unknown, assume it is correct
-        //       return Unknown type if types don't match
-        return left;
+    fn enum_variants(&self, t: TypeIdx) -> Vec<StringIdx> {
+        let Type::Enum(p) = self.comp.types.get(t) else { return Vec::new(); };
+        return self.comp.symbols.symbols().get(p)
+            .expect("should be an enum").decl_node.children.iter()
+            .filter_map(|v| match (v.t, v.value) {
+                (NodeType::EnumVariant, NodeValue::String(n)) => Some(n),
+                _ => None
+            })
+            .collect();
+    }
+
+    fn expect_block_type(&mut self, expected: TypeIdx, block: &AstNode) {
+        let value: &AstNode = block.children.last()
+            .filter(|s| s.t == NodeType::BlockValue)
+            .unwrap_or(block);
+        self.expect_type(expected, value);
+    }
+
+    fn check_deferred(&mut self, n: &AstNode, in_loop: bool) {
+        let escapes: Option<&str> = match n.t {
+            NodeType::Return => Some("return"),
+            NodeType::Break if !in_loop => Some("break"),
+            NodeType::Continue if !in_loop => Some("continue"),
+            _ => None
+        };
+        if let Some(escapes) = escapes {
+            self.comp.errors.push(Error::dynamic(
+                format!("'{}' cannot be used to leave deferred code", escapes),
+                n.source
+            ));
+        }
+        let in_loop: bool = in_loop || n.t == NodeType::While 
+            || n.t == NodeType::Loop;
+        n.children.iter().for_each(|c| self.check_deferred(c, in_loop));
+    }
+
+    // deferred code is copied to every point at which the block is left,
+    // in the reverse order of the 'defer' statements
+    fn lower_defers(&mut self, statements: Vec<AstNode>) -> Vec<AstNode> {
+        let mut lowered: Vec<AstNode> = Vec::new();
+        let mut deferred: Vec<AstNode> = Vec::new();
+        let mut diverges: bool = false;
+        for statement in statements {
+            if statement.t == NodeType::Defer {
+                deferred.insert(0, statement.children[0].clone());
+                continue;
+            }
+            diverges = matches!(
+                statement.t,
+                NodeType::Return | NodeType::Break | NodeType::Continue
+            );
+            lowered.push(self.insert_deferred(statement, &deferred, false));
+        }
+        if diverges || deferred.is_empty() { return lowered; }
+        let value: Option<AstNode> = lowered.last()
+            .filter(|s| s.t == NodeType::BlockValue).cloned();
+        let Some(value) = value else {
+            lowered.extend(deferred);
+            return lowered;
+        };
+        // the value of the block is computed before running deferred code
+        lowered.pop();
+        let name: StringIdx = self.comp.strings.insert("defer.value");
+        let path: PathIdx = self.comp.paths.insert(&[name]);
+        let scope: ScopeIdx = *self.scopes.last().expect("should be in a block");
+        self.comp.scopes.get_mut(scope).insert(
+            name, value.source, VariableState::Initialized, value.rtype, true
+        );
+        let unit: TypeIdx = self.comp.types.insert(Type::Unit);
+        let value_type: AstNode = AstNode::new(
+            NodeType::InferredType, value.source, NodeValue::None,
+            Vec::new(), value.rtype
+        );
+        lowered.push(AstNode::new(
+            NodeType::VariableDecl, value.source, NodeValue::String(name),
+            vec!(value_type, value.children[0].clone()), unit
+        ));
+        lowered.extend(deferred);
+        let t_args: AstNode = AstNode::new(
+            NodeType::ArgumentList, value.source, NodeValue::None,
+            Vec::new(), self.comp.types.insert(Type::Unknown)
+        );
+        let access: AstNode = AstNode::new(
+            NodeType::PathAccess, value.source, NodeValue::Path(path),
+            vec!(t_args), value.rtype
+        );
+        lowered.push(AstNode::new(
+            value.t, value.source, value.value, vec!(access), value.rtype
+        ));
+        return lowered;
+    }
+
+    fn insert_deferred(
+        &mut self, n: AstNode, deferred: &[AstNode], in_loop: bool
+    ) -> AstNode {
+        if deferred.is_empty() { return n; }
+        let unit: TypeIdx = self.comp.types.insert(Type::Unit);
+        let name: StringIdx = self.comp.strings.insert("defer.value");
+        let path: PathIdx = self.comp.paths.insert(&[name]);
+        let is_computed: bool = n.t == NodeType::Return
+            && n.children[0].value == NodeValue::Path(path);
+        match n.t {
+            NodeType::Return if !is_computed => {
+                // the returned value is computed before running deferred code
+                let return_type: TypeIdx = *self.returns.last()
+                    .expect("should be in a function");
+                let scope: ScopeIdx = self.comp.scopes.insert(Scope::new());
+                self.comp.scopes.get_mut(scope).insert(
+                    name, n.source, VariableState::Initialized, return_type,
+                    true
+                );
+                let value_type: AstNode = AstNode::new(
+                    NodeType::InferredType, n.source, NodeValue::None,
+                    Vec::new(), return_type
+                );
+                let mut statements: Vec<AstNode> = vec!(AstNode::new(
+                    NodeType::VariableDecl, n.source, NodeValue::String(name),
+                    vec!(value_type, n.children[0].clone()), unit
+                ));
+                statements.extend_from_slice(deferred);
+                let t_args: AstNode = AstNode::new(
+                    NodeType::ArgumentList, n.source, NodeValue::None,
+                    Vec::new(), self.comp.types.insert(Type::Unknown)
+                );
+                let value: AstNode = AstNode::new(
+                    NodeType::PathAccess, n.source, NodeValue::Path(path),
+                    vec!(t_args), return_type
+                );
+                statements.push(AstNode::new(
+                    n.t, n.source, n.value, vec!(value), unit
+                ));
+                return AstNode::new(
+                    NodeType::Block, n.source, NodeValue::Scope(scope),
+                    statements, unit
+                );
+            }
+            NodeType::Return |
+            NodeType::Break | NodeType::Continue if !in_loop || is_computed => {
+                let mut statements: Vec<AstNode> = deferred.to_vec();
+                statements.push(n.clone());
+                let scope: ScopeIdx = self.comp.scopes.insert(Scope::new());
+                return AstNode::new(
+                    NodeType::Block, n.source, NodeValue::Scope(scope),
+                    statements, unit
+                );
+            }
+            _ => {}
+        }
+        let in_loop: bool = in_loop || n.t == NodeType::While 
+            || n.t == NodeType::Loop;
+        let children: Vec<AstNode> = n.children.into_iter()
+            .map(|c| self.insert_deferred(c, deferred, in_loop))
+            .collect();
+        return AstNode::new(n.t, n.source, n.value, children, n.rtype);
+    }
+
+    fn check_pattern(
+        &mut self, n: &AstNode, value_type: TypeIdx, variants: &[StringIdx]
+    ) -> (AstNode, Option<(i128, i128)>) {
+        match n.t {
+            NodeType::Wildcard => return (n.clone(), None),
+            NodeType::VariantPattern => {
+                return self.check_variant_pattern(n, value_type, variants);
+            }
+            NodeType::Range | NodeType::InclusiveRange => {
+                let (start, start_value) = self.check_pattern(
+                    &n.children[0], value_type, variants
+                );
+                let (end, end_value) = self.check_pattern(
+                    &n.children[1], value_type, variants
+                );
+                let is_integer: bool = match self.comp.types.get(value_type) {
+                    Type::Unknown => true,
+                    t => t.is_integer()
+                };
+                let checked: AstNode = AstNode::new(
+                    n.t, n.source, n.value, vec!(start, end), value_type
+                );
+                if !is_integer {
+                    self.comp.errors.push(Error::dynamic(
+                        format!(
+                            "range patterns cannot be used on values of type '{}'",
+                            value_type.display(self.comp)
+                        ),
+                        n.source
+                    ));
+                    return (checked, None);
+                }
+                let (Some((start, _)), Some((end, _))) = (start_value, end_value)
+                    else { return (checked, None); };
+                let end: i128 = if n.t == NodeType::Range { end - 1 } else { end };
+                if start > end {
+                    self.comp.errors.push(Error::fixed(
+                        "the range pattern does not contain any values", n.source
+                    ));
+                    return (checked, None);
+                }
+                return (checked, Some((start, end)));
+            }
+            _ => {}
+        }
+        let checked: AstNode = self.check_node(n, Some(value_type));
+        let error_count: usize = self.comp.errors.len();
+        self.expect_type(value_type, &checked);
+        if self.comp.errors.len() > error_count { return (checked, None); }
+        let value: Option<i128> = match (checked.t, checked.value) {
+            (NodeType::IntegerLiteral, NodeValue::String(v)) =>
+                self.comp.strings.get(v).parse().ok(),
+            (NodeType::Negate, _) => match checked.children[0].value {
+                NodeValue::String(v) 
+                    if checked.children[0].t == NodeType::IntegerLiteral =>
+                    self.comp.strings.get(v).parse::<i128>().ok().map(|v| -v),
+                _ => None
+            },
+            (NodeType::CharLiteral, NodeValue::String(v)) =>
+                self.comp.strings.get(v).chars().next().map(|c| c as i128),
+            (NodeType::BooleanLiteral, NodeValue::String(v)) =>
+                Some((self.comp.strings.get(v) == "true") as i128),
+            (NodeType::PathAccess, NodeValue::Path(p)) => {
+                let is_variant: bool = self.comp.symbols.symbols().get(&p)
+                    .map(|s| s.decl_node.t == NodeType::EnumVariant)
+                    .unwrap_or(false);
+                let name: StringIdx = *self.comp.paths.get(p).last()
+                    .expect("has at least one segment");
+                if is_variant {
+                    variants.iter().position(|v| *v == name).map(|v| v as i128)
+                } else { None }
+            }
+            _ => None
+        };
+        if value.is_none() {
+            self.comp.errors.push(Error::fixed(
+                "patterns must be literals or enum variants", n.source
+            ));
+        }
+        return (checked, value.map(|v| (v, v)));
+    }
+
+    fn check_variant_pattern(
+        &mut self, n: &AstNode, value_type: TypeIdx, variants: &[StringIdx]
+    ) -> (AstNode, Option<(i128, i128)>) {
+        let unknown: TypeIdx = self.comp.types.insert(Type::Unknown);
+        let variant: &AstNode = &n.children[0];
+        let path: PathIdx = match variant.value {
+            NodeValue::Path(p) => p,
+            _ => unreachable!("should be a path")
+        };
+        let is_variant: bool = self.comp.symbols.symbols().get(&path)
+            .map(|s| s.decl_node.t == NodeType::EnumVariant)
+            .unwrap_or(false);
+        if !is_variant {
+            self.comp.errors.push(Error::dynamic(
+                format!("'{}' is not an enum variant", path.display(self.comp)),
+                variant.source
+            ));
+            return (n.clone(), None);
+        }
+        let mut enum_path: Vec<StringIdx> = self.comp.paths.get(path).into();
+        let name: StringIdx = enum_path.pop().expect("has at least one segment");
+        let enum_path: PathIdx = self.comp.paths.insert(&enum_path);
+        let variant: AstNode = AstNode::new(
+            variant.t, variant.source, variant.value, variant.children.clone(),
+            self.comp.types.insert(Type::Enum(enum_path))
+        );
+        let error_count: usize = self.comp.errors.len();
+        self.expect_type(value_type, &variant);
+        if self.comp.errors.len() > error_count { return (n.clone(), None); }
+        let payload: Vec<TypeIdx> = self.variant_payload(path)
+            .unwrap_or_default();
+        if payload.len() != n.children.len() - 1 {
+            self.comp.errors.push(Error::dynamic(
+                format!(
+                    "'{}' carries {} value{}, but the pattern has {}",
+                    path.display(self.comp),
+                    payload.len(),
+                    if payload.len() == 1 { "" } else { "s" },
+                    n.children.len() - 1
+                ),
+                n.source
+            ));
+        }
+        let mut children: Vec<AstNode> = vec!(variant);
+        for (i, value) in n.children[1..].iter().enumerate() {
+            let value_type: TypeIdx = *payload.get(i).unwrap_or(&unknown);
+            children.push(AstNode::new(
+                value.t, value.source, value.value, Vec::new(), value_type
+            ));
+        }
+        let checked: AstNode = AstNode::new(
+            n.t, n.source, n.value, children, value_type
+        );
+        let index: Option<i128> = variants.iter().position(|v| *v == name)
+            .map(|v| v as i128);
+        return (checked, index.map(|v| (v, v)));
+    }
+
+    fn declare_bindings(&mut self, pattern: &AstNode, is_constant: bool) {
+        match (pattern.t, pattern.value) {
+            (NodeType::Binding, NodeValue::String(name)) => {
+                let scope: ScopeIdx = *self.scopes.last()
+                    .expect("should be in a function");
+                self.comp.scopes.get_mut(scope).insert(
+                    name, pattern.source, VariableState::Initialized,
+                    pattern.rtype, is_constant
+                );
+            }
+            _ => pattern.children.iter()
+                .for_each(|c| self.declare_bindings(c, is_constant))
+        }
+    }
+
+    // 'for' loops become blocks containing a 'while' loop with a third
+    // child, which is executed after every iteration (including 'continue')
+    fn desugar_for(&mut self, n: &AstNode, name: StringIdx) -> AstNode {
+        let unknown: TypeIdx = self.comp.types.insert(Type::Unknown);
+        let node = |t: NodeType, value: NodeValue, children: Vec<AstNode>|
+            AstNode::new(t, n.source, value, children, unknown);
+        let access = |tc: &mut Self, name: &str| {
+            let name: StringIdx = tc.comp.strings.insert(name);
+            let path: PathIdx = tc.comp.paths.insert(&[name]);
+            node(NodeType::PathAccess, NodeValue::Path(path), vec!(
+                node(NodeType::ArgumentList, NodeValue::None, Vec::new())
+            ))
+        };
+        let declare = |
+            tc: &mut Self, name: &str, is_constant: bool, t: TypeIdx,
+            value: AstNode
+        | {
+            let name: StringIdx = tc.comp.strings.insert(name);
+            let mut children: Vec<AstNode> = Vec::new();
+            if is_constant {
+                children.push(node(
+                    NodeType::IsConstant, NodeValue::None, Vec::new()
+                ));
+            }
+            children.push(AstNode::new(
+                NodeType::InferredType, value.source, NodeValue::None,
+                Vec::new(), t
+            ));
+            children.push(value);
+            node(NodeType::VariableDecl, NodeValue::String(name), children)
+        };
+        let iterated: &AstNode = &n.children[0];
+        let var_name: String = String::from(self.comp.strings.get(name));
+        let mut body: AstNode = n.children[1].clone();
+        let mut statements: Vec<AstNode> = Vec::new();
+        let condition: AstNode;
+        let mut step: Vec<AstNode> = Vec::new();
+        // the bounds are only checked here to find the type of the variable,
+        // their errors are reported when checking the desugared loop
+        let error_count: usize = self.comp.errors.len();
+        match iterated.t {
+            NodeType::Range | NodeType::InclusiveRange => {
+                let start: AstNode = self.check_node(&iterated.children[0], None);
+                let end: AstNode = self.check_node(
+                    &iterated.children[1], Some(start.rtype)
+                );
+                self.comp.errors.truncate(error_count);
+                let mut var_type: TypeIdx = self.match_types(
+                    start.rtype, end.rtype
+                );
+                if *self.comp.types.get(var_type) == Type::Integer {
+                    var_type = self.comp.types.insert(Type::Usize);
+                }
+                let is_integer: bool = self.comp.types.get(var_type).is_integer();
+                if (var_type != unknown && !is_integer) || (var_type == unknown
+                        && start.rtype != unknown && end.rtype != unknown) {
+                    self.comp.errors.push(Error::dynamic(
+                        format!(
+                            concat!(
+                                "the bounds of a range must be integers of ",
+                                "the same type, but are of type '{}' and '{}'"
+                            ),
+                            start.rtype.display(self.comp),
+                            end.rtype.display(self.comp)
+                        ),
+                        iterated.source
+                    ));
+                }
+                let step_value: AstNode = match iterated.children.get(2) {
+                    Some(step) => step.clone(),
+                    None => node(
+                        NodeType::IntegerLiteral,
+                        NodeValue::String(self.comp.strings.insert("1")),
+                        Vec::new()
+                    )
+                };
+                // steps that are not known at compile time are only
+                // rejected if they are obviously negative
+                let step_count: usize = self.comp.errors.len();
+                let checked_step: AstNode = self.check_node(
+                    &step_value, Some(var_type)
+                );
+                self.comp.errors.truncate(step_count);
+                let is_positive: bool = match eval_constant(
+                    self.comp, &checked_step, &self.scopes
+                ) {
+                    Ok(Constant::Integer(v)) => v > 0,
+                    _ => step_value.t != NodeType::Negate
+                };
+                if !is_positive {
+                    self.comp.errors.push(Error::fixed(
+                        "the step of a range must be positive", step_value.source
+                    ));
+                }
+                statements.push(declare(
+                    self, &var_name, false, var_type, iterated.children[0].clone()
+                ));
+                statements.push(declare(
+                    self, "for.end", true, var_type, iterated.children[1].clone()
+                ));
+                statements.push(declare(self, "for.step", true, var_type, step_value));
+                let is_inclusive: bool = iterated.t == NodeType::InclusiveRange;
+                condition = node(
+                    if is_inclusive { NodeType::LessThanEqual }
+                        else { NodeType::LessThan },
+                    NodeValue::None,
+                    vec!(access(self, &var_name), access(self, "for.end"))
+                );
+                // 'end - i' can't overflow, unlike 'i + step'
+                let remaining: AstNode = node(
+                    NodeType::Subtract, NodeValue::None,
+                    vec!(access(self, "for.end"), access(self, &var_name))
+                );
+                let is_last: AstNode = node(
+                    if is_inclusive { NodeType::LessThan } 
+                        else { NodeType::LessThanEqual },
+                    NodeValue::None,
+                    vec!(remaining, access(self, "for.step"))
+                );
+                step.push(node(NodeType::If, NodeValue::None, vec!(
+                    is_last,
+                    node(NodeType::Block, NodeValue::None, vec!(
+                        node(NodeType::Break, NodeValue::None, Vec::new())
+                    )),
+                    node(NodeType::Block, NodeValue::None, Vec::new())
+                )));
+                step.push(node(NodeType::AssignAdd, NodeValue::None, vec!(
+                    access(self, &var_name), access(self, "for.step")
+                )));
+            }
+            _ => {
+                let checked: AstNode = self.check_node(iterated, None);
+                self.comp.errors.truncate(error_count);
+                let mut iterated_type: TypeIdx = checked.rtype;
+                let element_type: TypeIdx = match *self.comp.types
+                    .get(checked.rtype) {
+                    Type::Array(_, t) | Type::Slice(_, t) => t,
+                    Type::Unknown => unknown,
+                    _ => {
+                        iterated_type = unknown;
+                        self.comp.errors.push(Error::dynamic(
+                            format!(
+                                "a value of type '{}' cannot be iterated over",
+                                checked.rtype.display(self.comp)
+                            ),
+                            iterated.source
+                        ));
+                        unknown
+                    }
+                };
+                let usize_type: TypeIdx = self.comp.types.insert(Type::Usize);
+                statements.push(declare(
+                    self, "for.iterated", true, iterated_type, iterated.clone()
+                ));
+                let zero: AstNode = node(
+                    NodeType::IntegerLiteral,
+                    NodeValue::String(self.comp.strings.insert("0")),
+                    Vec::new()
+                );
+                statements.push(declare(self, "for.index", false, usize_type, zero));
+                let len: StringIdx = self.comp.strings.insert("len");
+                condition = node(NodeType::LessThan, NodeValue::None, vec!(
+                    access(self, "for.index"),
+                    node(NodeType::MemberAccess, NodeValue::String(len), vec!(
+                        access(self, "for.iterated")
+                    ))
+                ));
+                let element: AstNode = node(NodeType::Index, NodeValue::None, vec!(
+                    access(self, "for.iterated"), access(self, "for.index")
+                ));
+                body = node(NodeType::Block, NodeValue::None, vec!(
+                    declare(self, &var_name, true, element_type, element),
+                    body
+                ));
+                let one: AstNode = node(
+                    NodeType::IntegerLiteral,
+                    NodeValue::String(self.comp.strings.insert("1")),
+                    Vec::new()
+                );
+                step.push(node(NodeType::AssignAdd, NodeValue::None, vec!(
+                    access(self, "for.index"), one
+                )));
+            }
+        }
+        statements.push(node(NodeType::While, NodeValue::None, vec!(
+            condition, body, node(NodeType::Block, NodeValue::None, step)
+        )));
+        return node(NodeType::Block, NodeValue::None, statements);
+    }
+
+    fn check_integer_literal(
+        &mut self, n: &AstNode, e: Option<TypeIdx>, is_negated: bool
+    ) -> AstNode {
+        let rtype: TypeIdx = match n.children.first() {
+            Some(suffix) => self.check_type(suffix),
+            None => e
+                .filter(|e| self.comp.types.get(*e).is_integer())
+                .unwrap_or_else(|| self.comp.types.insert(Type::Integer))
+        };
+        let NodeValue::String(v) = n.value 
+            else { unreachable!("node must be valid") };
+        let mut value: Option<i128> = self.comp.strings.get(v).parse().ok();
+        if is_negated { value = value.map(|v| -v); }
+        let range: Option<(i128, i128)> = integer_range(
+            self.comp, self.comp.types.get(rtype)
+        );
+        let fits: bool = match (value, range) {
+            (Some(v), Some((min, max))) => min <= v && v <= max,
+            (Some(_), None) => true,
+            (None, _) => false
+        };
+        if !fits {
+            self.comp.errors.push(Error::dynamic(
+                format!(
+                    "the value {}{} does not fit into the type '{}'",
+                    if is_negated { "-" } else { "" },
+                    self.comp.strings.get(v), rtype.display(self.comp)
+                ),
+                n.source
+            ));
+        }
+        return AstNode::new(n.t, n.source, n.value, n.children.clone(), rtype);
+    }
+
+    fn check_shift(&mut self, value: &AstNode, amount: &AstNode) {
+        let is_unknown: bool = *self.comp.types.get(value.rtype) == Type::Unknown;
+        if !self.is_integer(value.rtype) && !is_unknown {
+            self.comp.errors.push(Error::dynamic(
+                format!(
+                    "a value of type '{}' cannot be shifted",
+                    value.rtype.display(self.comp)
+                ),
+                value.source
+            ));
+            return;
+        }
+        let is_unsigned: bool = matches!(
+            self.comp.types.get(amount.rtype),
+            Type::Unknown | Type::Integer |
+            Type::U8 | Type::U16 | Type::U32 | Type::U64 | Type::Usize
+        );
+        if !is_unsigned {
+            self.comp.errors.push(Error::dynamic(
+                format!(
+                    concat!(
+                        "the amount to shift by must be an unsigned integer, ",
+                        "but is of type '{}'"
+                    ),
+                    amount.rtype.display(self.comp)
+                ),
+                amount.source
+            ));
+            return;
+        }
+        if amount.t == NodeType::Negate {
+            self.comp.errors.push(Error::fixed(
+                "the amount to shift by must not be negative", amount.source
+            ));
+            return;
+        }
+        let constant_amount: Option<u64> = match amount.value {
+            NodeValue::String(v) if amount.t == NodeType::IntegerLiteral =>
+                self.comp.strings.get(v).parse().ok(),
+            _ => None
+        };
+        let bits: Option<u64> = integer_type_bits(
+            self.comp, self.comp.types.get(value.rtype)
+        );
+        if let (Some(a), Some(bits)) = (constant_amount, bits) {
+            if a >= bits {
+                self.comp.errors.push(Error::dynamic(
+                    format!(
+                        concat!(
+                            "a value of type '{}' cannot be shifted by {} ",
+                            "bits, since it only has {} bits"
+                        ),
+                        value.rtype.display(self.comp), a, bits
+                    ),
+                    amount.source
+                ));
+            }
+        }
+    }
+
+    // operators on structs call the function of a well-known interface
+    fn check_operator(&mut self, n: &AstNode, operand: &AstNode) -> Option<AstNode> {
+        // template parameters are not known to be structs
+        let owner: Option<PathIdx> = match self.comp.types.get(operand.rtype) {
+            Type::Struct(p, _) => Some(*p),
+            Type::Param(_, _) => None,
+            _ => return None
+        };
+        let (interface_name, function_name, operator) = operator_interface(n.t)?;
+        let unknown: TypeIdx = self.comp.types.insert(Type::Unknown);
+        let boolean: TypeIdx = self.comp.types.insert(Type::Boolean);
+        let interface_segs: Vec<StringIdx> = ["std", "ops", interface_name]
+            .iter().map(|s| self.comp.strings.insert(s)).collect();
+        let interface: PathIdx = self.comp.paths.insert(&interface_segs);
+        let function: StringIdx = self.comp.strings.insert(function_name);
+        let method: Option<(PathIdx, AstNode)> = match owner {
+            Some(owner) if self.comp.symbols.vtables()
+                .contains_key(&(owner, interface)) =>
+                self.find_method(operand.rtype, function),
+            _ => None
+        };
+        let is_param: bool = owner.is_none();
+        if is_param && self.satisfies(operand.rtype, Constraint::Interface(interface)) {
+            let mut children: Vec<AstNode> = vec!(operand.clone());
+            for child in &n.children[1..] {
+                let child: AstNode = self.check_node(child, Some(operand.rtype));
+                self.expect_type(operand.rtype, &child);
+                children.push(child);
+            }
+            let rtype: TypeIdx = match interface_name {
+                "Equal" | "Ordered" => boolean,
+                _ => operand.rtype
+            };
+            return Some(AstNode::new(n.t, n.source, n.value, children, rtype));
+        }
+        let Some((called, decl_node)) = method else {
+            self.comp.errors.push(Error::dynamic(
+                format!(
+                    concat!(
+                        "the operator '{}' cannot be used on values of type ",
+                        "'{}', since it does not implement the interface '{}'"
+                    ),
+                    operator, operand.rtype.display(self.comp),
+                    interface.display(self.comp)
+                ),
+                n.source
+            ));
+            let mut children: Vec<AstNode> = vec!(operand.clone());
+            children.extend(n.children[1..].iter()
+                .map(|c| self.check_node(c, None))
+            );
+            return Some(AstNode::new(n.t, n.source, n.value, children, unknown));
+        };
+        let call: AstNode = AstNode::new(
+            NodeType::Call, n.source, NodeValue::None,
+            vec!(
+                AstNode::new(
+                    NodeType::MemberAccess, n.source, NodeValue::String(function),
+                    vec!(operand.clone()), unknown
+                ),
+                AstNode::new(
+                    NodeType::ArgumentList, n.source, NodeValue::None,
+                    n.children[1..].into(), unknown
+                )
+            ),
+            unknown
+        );
+        let call: AstNode = self.check_call(
+            &call, called, &decl_node, Some(operand.clone())
+        );
+        return Some(match n.t {
+            NodeType::NotEqual => AstNode::new(
+                NodeType::LogicalNot, n.source, NodeValue::None,
+                vec!(call), boolean
+            ),
+            // the result of 'compare' is compared to zero
+            NodeType::LessThan | NodeType::GreaterThan
+                | NodeType::LessThanEqual | NodeType::GreaterThanEqual => {
+                let zero: AstNode = AstNode::new(
+                    NodeType::IntegerLiteral, n.source,
+                    NodeValue::String(self.comp.strings.insert("0")),
+                    Vec::new(), call.rtype
+                );
+                AstNode::new(n.t, n.source, n.value, vec!(call, zero), boolean)
+            }
+            _ => call
+        });
+    }
+
+    fn report_operands(&mut self, n: &AstNode, left: &AstNode, right: &AstNode) {
+        self.comp.errors.push(Error::dynamic(
+            format!(
+                "the operator '{}' cannot be used on values of type '{}' and '{}'",
+                operator_symbol(n.t).expect("should be an operator"),
+                left.rtype.display(self.comp), right.rtype.display(self.comp)
+            ),
+            n.source
+        ));
+    }
+
+    pub fn match_types(&mut self, left: TypeIdx, right: TypeIdx) -> TypeIdx {
+        // if any type is unknown, assume it is correct
+        // return Unknown type if types don't match
+        if left == right { return left; }
+        let unknown: TypeIdx = self.comp.types.insert(Type::Unknown);
+        let l: Type = self.comp.types.get(left).clone();
+        let r: Type = self.comp.types.get(right).clone();
+        return match (l, r) {
+            (Type::Unknown, _) => right,
+            (_, Type::Unknown) => left,
+            (Type::Integer, r) if r.is_integer() => right,
+            (l, Type::Integer) if l.is_integer() => left,
+            (Type::Float, r) if r.is_float() => right,
+            (l, Type::Float) if l.is_float() => left,
+            // literals may be used as values of constrained template parameters
+            (Type::Integer, Type::Param(_, c)) 
+                if c.contains(&Constraint::Integer) => right,
+            (Type::Param(_, c), Type::Integer) 
+                if c.contains(&Constraint::Integer) => left,
+            (Type::Float, Type::Param(_, c)) 
+                if c.contains(&Constraint::Float) => right,
+            (Type::Param(_, c), Type::Float) 
+                if c.contains(&Constraint::Float) => left,
+            (Type::Pointer(lc, lt), Type::Pointer(rc, rt)) => {
+                match self.match_pointees(lt, rt) {
+                    Some(t) => self.comp.types.insert(Type::Pointer(lc || rc, t)),
+                    None => unknown
+                }
+            }
+            (Type::Nullable(_), Type::Null) => left,
+            (Type::Null, Type::Nullable(_)) => right,
+            (Type::Pointer(_, _), Type::Null) => self.comp.types
+                .insert(Type::Nullable(left)),
+            (Type::Null, Type::Pointer(_, _)) => self.comp.types
+                .insert(Type::Nullable(right)),
+            (Type::Nullable(lt), Type::Nullable(rt)) => {
+                let matched: TypeIdx = self.match_types(lt, rt);
+                if matched == unknown { unknown }
+                    else { self.comp.types.insert(Type::Nullable(matched)) }
+            }
+            (Type::Nullable(lt), Type::Pointer(_, _)) => {
+                let matched: TypeIdx = self.match_types(lt, right);
+                if matched == unknown { unknown }
+                    else { self.comp.types.insert(Type::Nullable(matched)) }
+            }
+            (Type::Pointer(_, _), Type::Nullable(rt)) => {
+                let matched: TypeIdx = self.match_types(left, rt);
+                if matched == unknown { unknown }
+                    else { self.comp.types.insert(Type::Nullable(matched)) }
+            }
+            (Type::Reference(lc, lt), Type::Reference(rc, rt)) => {
+                match self.match_pointees(lt, rt) {
+                    Some(t) => self.comp.types.insert(Type::Reference(lc || rc, t)),
+                    None => unknown
+                }
+            }
+            (Type::Slice(lc, lt), Type::Slice(rc, rt)) => {
+                match self.match_pointees(lt, rt) {
+                    Some(t) => self.comp.types.insert(Type::Slice(lc || rc, t)),
+                    None => unknown
+                }
+            }
+            (Type::Tuple(l), Type::Tuple(r)) if l.len() == r.len() => {
+                let mut elements: Vec<TypeIdx> = Vec::new();
+                for (l, r) in l.into_iter().zip(r) {
+                    let matched: TypeIdx = self.match_types(l, r);
+                    if matched == unknown && l != unknown && r != unknown {
+                        return unknown;
+                    }
+                    elements.push(matched);
+                }
+                self.comp.types.insert(Type::Tuple(elements))
+            }
+            (Type::Struct(lp, l), Type::Struct(rp, r))
+                    if lp == rp && l.len() == r.len() => {
+                let mut t_args: Vec<TypeIdx> = Vec::new();
+                for (l, r) in l.into_iter().zip(r) {
+                    let matched: TypeIdx = self.match_types(l, r);
+                    if matched == unknown && l != unknown && r != unknown {
+                        return unknown;
+                    }
+                    t_args.push(matched);
+                }
+                self.comp.types.insert(Type::Struct(lp, t_args))
+            }
+            (Type::Array(ll, lt), Type::Array(rl, rt)) if ll == rl => {
+                match self.match_pointees(lt, rt) {
+                    Some(t) => self.comp.types.insert(Type::Array(ll, t)),
+                    None => unknown
+                }
+            }
+            _ => unknown
+        };
+    }
+
+    fn match_pointees(&mut self, left: TypeIdx, right: TypeIdx) -> Option<TypeIdx> {
+        let unknown: TypeIdx = self.comp.types.insert(Type::Unknown);
+        let implements = |tc: &Self, s: PathIdx, i: PathIdx| 
+            tc.comp.symbols.vtables().contains_key(&(s, i));
+        match (self.comp.types.get(left), self.comp.types.get(right)) {
+            (Type::Interface(i), Type::Struct(s, _)) 
+                if implements(self, *s, *i) => return Some(left),
+            (Type::Struct(s, _), Type::Interface(i)) 
+                if implements(self, *s, *i) => return Some(right),
+            _ => {}
+        }
+        let matched: TypeIdx = self.match_types(left, right);
+        if matched == unknown && left != unknown && right != unknown {
+            return None;
+        }
+        return Some(matched);
     }
 }
\ No newline at end of file
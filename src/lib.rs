@@ -28,6 +28,8 @@ pub struct Compiler {
     pub(crate) paths: PathMap,
     pub(crate) file_contents: HashMap<StringIdx, String>,
     parsed_files: HashMap<StringIdx, Vec<AstNode>>,
+    // properties of the target and flags that conditions may check
+    pub(crate) properties: HashMap<String, String>,
     pub symbols: SymbolTable,
     pub types: TypeMap,
     pub scopes: ScopeMap,
@@ -37,17 +39,24 @@ pub struct Compiler {
 
 impl Compiler {
     pub fn new() -> Compiler {
-        return Compiler { 
+        let mut c: Compiler = Compiler { 
             strings: StringMap::new(),
             paths: PathMap::new(),
             file_contents: HashMap::new(),
             parsed_files: HashMap::new(),
+            properties: HashMap::from([
+                (String::from("os"), String::from(std::env::consts::OS)),
+                (String::from("arch"), String::from(std::env::consts::ARCH)),
+                (String::from("pointer_width"), usize::BITS.to_string())
+            ]),
             symbols: SymbolTable::new(),
             types: TypeMap::new(),
             scopes: ScopeMap::new(),
             errors: Vec::new(),
             error_stage: ERR_PARSING
         };
+        c.parse(OPERATORS_PATH, String::from(OPERATORS_SOURCE));
+        return c;
     }
 
     pub fn parse(&mut self, path: &str, content: String) {
@@ -70,21 +79,48 @@ impl Compiler {
 
     pub fn errors(&self) -> &[Error] { &self.errors }
 
+    pub fn define(&mut self, name: &str, value: &str) {
+        self.properties.insert(String::from(name), String::from(value));
+    }
+
+    // the width of pointers and 'usize' in bits, which may be overridden
+    fn target_pointer_width(&self) -> Option<u64> {
+        return self.properties.get("pointer_width")
+            .and_then(|w| w.parse().ok())
+            .filter(|w| matches!(w, 16 | 32 | 64));
+    }
+
+    pub(crate) fn pointer_width(&self) -> u64 {
+        return self.target_pointer_width()
+            .expect("should be checked before type checking");
+    }
+
     pub fn check_types(&mut self) {
         if self.error_stage < ERR_TYPES && self.errors.len() > 0 {
             return;
         }
         self.error_stage = ERR_TYPES;
         self.errors.clear();
+        if self.target_pointer_width().is_none() {
+            self.errors.push(Error::message(format!(
+                "the pointer width '{}' is not supported (expected 16, 32 or 64)",
+                self.properties.get("pointer_width").map_or("", |w| w.as_str())
+            )));
+            return;
+        }
         let parsed_nodes: Vec<Vec<AstNode>> = self
             .parsed_files.values().cloned().collect();
+        let included_nodes: Vec<Vec<AstNode>> = parsed_nodes.iter()
+            .map(|nodes| included_nodes(self, nodes))
+            .collect();
+        if !self.errors.is_empty() { return; }
         let mut symbols: SymbolTable = SymbolTable::new();
-        for nodes in parsed_nodes {
-            symbols.insert_file(&nodes, self);
+        for nodes in &included_nodes {
+            symbols.insert_file(nodes, self);
         }
         self.symbols = symbols;
         if self.errors.len() > 0 { return; }
-        expand_paths(self);
+        expand_paths(self, &included_nodes);
         let mut tc: TypeChecker = TypeChecker::new(self);
         tc.check_types();
         if self.errors.len() > 0 { return; }
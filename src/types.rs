@@ -1,6 +1,6 @@
 use std::{collections::HashMap, rc::Rc};
 
-use crate::{PathIdx, Source, StringIdx};
+use crate::{Compiler, Constant, PathIdx, Source, StringIdx};
 
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)] 
@@ -15,13 +15,92 @@ pub enum Type {
     Boolean,
     Pointer(bool, TypeIdx),
     Reference(bool, TypeIdx),
-    Struct(PathIdx),
+    Nullable(TypeIdx),
+    Null,
+    Array(u64, TypeIdx),
+    Slice(bool, TypeIdx),
+    Tuple(Vec<TypeIdx>),
+    Struct(PathIdx, Vec<TypeIdx>),
+    Interface(PathIdx),
+    Enum(PathIdx),
+    // a template parameter, which is only known by its constraints
+    Param(StringIdx, Vec<Constraint>)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Constraint {
+    Interface(PathIdx),
+    Integer,
+    Float
+}
+
+impl Type {
+    pub fn is_integer(&self) -> bool {
+        return matches!(
+            self,
+            Type::Integer |
+            Type::U8 | Type::U16 | Type::U32 | Type::U64 | Type::Usize |
+            Type::S8 | Type::S16 | Type::S32 | Type::S64
+        );
+    }
+
+    pub fn is_float(&self) -> bool {
+        return matches!(self, Type::Float | Type::F32 | Type::F64);
+    }
 }
 
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct TypeIdx(usize);
 
+impl TypeIdx {
+    pub fn display(&self, c: &Compiler) -> String {
+        return match c.types.get(*self) {
+            Type::Unknown => String::from("<unknown>"),
+            Type::Integer => String::from("<integer>"),
+            Type::U8 => String::from("u8"),
+            Type::U16 => String::from("u16"),
+            Type::U32 => String::from("u32"),
+            Type::U64 => String::from("u64"),
+            Type::Usize => String::from("usize"),
+            Type::S8 => String::from("s8"),
+            Type::S16 => String::from("s16"),
+            Type::S32 => String::from("s32"),
+            Type::S64 => String::from("s64"),
+            Type::Float => String::from("<float>"),
+            Type::F32 => String::from("f32"),
+            Type::F64 => String::from("f64"),
+            Type::Unit => String::from("unit"),
+            Type::Boolean => String::from("bool"),
+            Type::Pointer(is_const, t) => format!(
+                "*{}{}", if *is_const { "const " } else { "" }, t.display(c)
+            ),
+            Type::Reference(is_const, t) => format!(
+                "&{}{}", if *is_const { "const " } else { "" }, t.display(c)
+            ),
+            Type::Nullable(t) => format!("?{}", t.display(c)),
+            Type::Null => String::from("null"),
+            Type::Array(length, t) => format!("[{}]{}", length, t.display(c)),
+            Type::Slice(is_const, t) => format!(
+                "[]{}{}", if *is_const { "const " } else { "" }, t.display(c)
+            ),
+            Type::Tuple(elements) => format!(
+                "({})",
+                elements.iter().map(|e| e.display(c))
+                    .collect::<Vec<String>>().join(", ")
+            ),
+            Type::Struct(p, t_args) if !t_args.is_empty() => format!(
+                "{}[{}]",
+                p.display(c),
+                t_args.iter().map(|t| t.display(c))
+                    .collect::<Vec<String>>().join(", ")
+            ),
+            Type::Struct(p, _) | Type::Interface(p) | Type::Enum(p) => p.display(c),
+            Type::Param(name, _) => String::from(c.strings.get(*name))
+        };
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TypeMap {
     indices: HashMap<Rc<Type>, TypeIdx>,
@@ -71,14 +150,18 @@ impl VariableState {
 pub struct Variable {
     pub source: Source, 
     pub state: VariableState,
-    pub value_type: TypeIdx
+    pub value_type: TypeIdx,
+    pub is_constant: bool,
+    // the value of constants that are known at compile time
+    pub value: Option<Constant>
 }
 
 impl Variable {
     pub fn new(
-        source: Source, state: VariableState, value_type: TypeIdx
+        source: Source, state: VariableState, value_type: TypeIdx,
+        is_constant: bool
     ) -> Variable {
-        return Variable { source, state, value_type };
+        return Variable { source, state, value_type, is_constant, value: None };
     }
 }
 
@@ -98,14 +181,24 @@ impl Scope {
     pub fn insert(
         &mut self, 
         name: StringIdx, 
-        source: Source, state: VariableState, value_type: TypeIdx
+        source: Source, state: VariableState, value_type: TypeIdx,
+        is_constant: bool
     ) {
+        let variable: Variable = Variable::new(
+            source, state, value_type, is_constant
+        );
         if let Some(v) = self.variables.get_mut(&name) {
-            v.push(Variable::new(source, state, value_type));
+            v.push(variable);
         } else {
-            self.variables.insert(
-                name, vec!(Variable::new(source, state, value_type))
-            );
+            self.variables.insert(name, vec!(variable));
+        }
+    }
+
+    pub fn set_value(&mut self, name: StringIdx, value: Constant) {
+        let variable: Option<&mut Variable> = self.variables.get_mut(&name)
+            .and_then(|v| v.last_mut());
+        if let Some(variable) = variable {
+            variable.value = Some(value);
         }
     }
 
@@ -136,4 +229,18 @@ impl ScopeMap {
             scopes: Vec::new()
         }
     }
+
+    pub fn insert(&mut self, scope: Scope) -> ScopeIdx {
+        let idx = ScopeIdx(self.scopes.len());
+        self.scopes.push(scope);
+        return idx;
+    }
+
+    pub fn get(&self, idx: ScopeIdx) -> &Scope {
+        return &self.scopes[idx.0];
+    }
+
+    pub fn get_mut(&mut self, idx: ScopeIdx) -> &mut Scope {
+        return &mut self.scopes[idx.0];
+    }
 }
\ No newline at end of file
@@ -0,0 +1,99 @@
+mod common;
+
+use common::*;
+
+#[test]
+fn literals_and_indexing() {
+    assert_ok(concat!(
+        "mod m; var a [4]u32 = [1, 2, 3, 4];\n",
+        "fun f(i usize, b [2][3]u16): u32 {\n",
+        "    const x u32 = a[i] + a[i + 1] + a[3];\n",
+        "    const y u16 = b[1][i];\n",
+        "    return x + y as u32;\n",
+        "}\n"
+    ));
+}
+
+#[test]
+fn literal_length_must_match() {
+    assert_error(
+        "mod m; var a [4]u8 = [1, 2, 3];",
+        "expected a value of type '[4]u8', but got a value of type '[3]u8'"
+    );
+}
+
+#[test]
+fn constant_index_out_of_bounds() {
+    assert_error(
+        "mod m; fun f(a [4]u32): u32 { return a[4]; }",
+        "the index 4 is out of bounds for an array of length 4"
+    );
+    assert_error(
+        "mod m; const n usize = 2; fun f(a [4]u32): u32 { return a[n * 2]; }",
+        "the index 4 is out of bounds for an array of length 4"
+    );
+}
+
+#[test]
+fn index_is_an_expression() {
+    assert_ok("mod m; fun f(a [4]u32, p *usize): u32 { return a[*p]; }");
+    assert_error(
+        "mod m; fun f(a [4]u32, x u32): u32 { return a[&x]; }",
+        "expected a value of type 'usize', but got a value of type '*u32'"
+    );
+}
+
+#[test]
+fn only_arrays_can_be_indexed() {
+    assert_error(
+        "mod m; fun f(a u32, i usize): u32 { return a[i]; }",
+        "a value of type 'u32' cannot be indexed"
+    );
+}
+
+#[test]
+fn template_arguments_are_not_indices() {
+    assert_ok(concat!(
+        "mod m; fun g[T](x T): T { return x; }\n",
+        "fun f(): u32 { return g[u32](1); }"
+    ));
+}
+
+#[test]
+fn variables_take_a_single_index() {
+    assert_error(
+        "mod m; fun f(a [4]u32): u32 { return a[u8, u16]; }",
+        "a variable can only be indexed with a single value"
+    );
+}
+
+#[test]
+fn array_sizes() {
+    assert_ok(concat!(
+        "mod m; struct Header(magic [4]u8, version u16, flags u32);\n",
+        "static_assert(sizeof [4]u32 == 16);\n",
+        "static_assert(sizeof [3]u16 == 6);\n",
+        "static_assert(sizeof [0]u64 == 0);\n",
+        "static_assert(sizeof Header == 12);\n",
+        "static_assert(sizeof [2]Header == 24);\n"
+    ));
+}
+
+#[test]
+fn sizes_of_template_structs() {
+    assert_ok(concat!(
+        "mod m; struct Box[T](v T); struct Pair[A, B](a A, b B);\n",
+        "static_assert(sizeof Box[u8] == 1);\n",
+        "static_assert(sizeof Box[u64] == 8);\n",
+        "static_assert(sizeof Pair[u8, Box[u32]] == 8);\n",
+        "static_assert(sizeof Pair[Box[u8], Box[u8]] == 2);\n"
+    ));
+}
+
+#[test]
+fn unknown_sizes() {
+    assert_error(
+        "mod m; struct Node(next Node); static_assert(sizeof Node == 0);",
+        "the size of the type is not known at compile time"
+    );
+}
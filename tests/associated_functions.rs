@@ -0,0 +1,24 @@
+mod common;
+
+use common::*;
+
+#[test]
+fn declared_under_struct() {
+    assert_ok(concat!(
+        "mod m; struct S(n u32);\n",
+        "fun S::get(s *const S): u32 { return s.n; }\n",
+        "fun f(s S): u32 { return S::get(&s); }\n"
+    ));
+}
+
+#[test]
+fn owner_must_be_struct() {
+    assert_error(
+        "mod m; enum E { A } fun E::f() {}",
+        "'m::E::f' can only be declared if 'm::E' is a struct in the same module"
+    );
+    assert_error(
+        "mod m; fun Nope::f() {}",
+        "'m::Nope::f' can only be declared if 'm::Nope' is a struct in the same module"
+    );
+}
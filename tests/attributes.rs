@@ -0,0 +1,105 @@
+mod common;
+
+use common::*;
+use tolac::{AstNode, Compiler};
+
+fn declaration(c: &Compiler, path: &str) -> AstNode {
+    return c.symbols.symbols().iter()
+        .find(|(p, _)| p.display(c) == path)
+        .map(|(_, s)| s.decl_node.clone())
+        .expect("should be declared");
+}
+
+#[test]
+fn stored_on_declarations() {
+    let mut c: Compiler = Compiler::new();
+    let errors: Vec<String> = check(&mut c, concat!(
+        "mod m;\n",
+        "@inline @cold\n",
+        "fun f() {}\n",
+        "@packed @align(16) @deprecated(\"use T\")\n",
+        "struct S(@align(8) a u8, @deprecated b u32);\n",
+        "@align(4) @link_name(\"gv\")\n",
+        "exp var g u32 = 0;\n",
+        "@link_name(\"puts2\")\n",
+        "ext fun puts(s *const u8): s32;\n",
+        "fun h() { @align(8) var x u32 = 0; }\n"
+    ));
+    assert!(errors.is_empty(), "unexpected errors: {:#?}", errors);
+    let f: AstNode = declaration(&c, "m::f");
+    assert!(f.attribute(&c, "inline").is_some());
+    assert!(f.attribute(&c, "cold").is_some());
+    assert!(f.attribute(&c, "packed").is_none());
+    let s: AstNode = declaration(&c, "m::S");
+    assert_eq!(
+        s.attribute(&c, "align").map(|a| a.children.len()),
+        Some(1)
+    );
+    assert_eq!(
+        s.attribute(&c, "deprecated").map(|a| a.children.len()),
+        Some(1)
+    );
+    assert!(declaration(&c, "m::g").attribute(&c, "link_name").is_some());
+    assert!(declaration(&c, "m::puts").attribute(&c, "link_name").is_some());
+}
+
+#[test]
+fn unknown_attributes() {
+    assert_error("mod m; @whatever fun y() {}", "'@whatever' is not a known attribute");
+    assert_error(
+        "mod m; @cold @cold fun z() {}",
+        "the attribute '@cold' is specified more than once"
+    );
+}
+
+#[test]
+fn misplaced_attributes() {
+    assert_error(
+        "mod m; @packed fun f() {}",
+        "the attribute '@packed' cannot be applied to functions"
+    );
+    assert_error(
+        "mod m; fun f(@inline a u32) {}",
+        "the attribute '@inline' cannot be applied to parameters"
+    );
+    assert_error(
+        "mod m; struct S(@inline a u8);",
+        "the attribute '@inline' cannot be applied to fields"
+    );
+    assert_error(
+        "mod m; @inline enum E u8 { A }",
+        "the attribute '@inline' cannot be applied to enums"
+    );
+    assert_error(
+        "mod m; fun h() { @link_name(\"y\") var x u32 = 0; }",
+        "the attribute '@link_name' cannot be applied to local variables"
+    );
+    assert_error(
+        "mod m; fun h() { @cold if true {} }",
+        "the attribute '@cold' cannot be applied to statements"
+    );
+    assert_error(
+        "mod m; @link_name(\"x\") var v u32 = 0;",
+        "the attribute '@link_name' can only be applied to external or exported declarations"
+    );
+}
+
+#[test]
+fn arguments() {
+    assert_error(
+        "mod m; @align(3) struct S(a u8);",
+        "the attribute '@align' expects an integer literal that is a power of two"
+    );
+    assert_error(
+        "mod m; @link_name(5) ext fun f();",
+        "the attribute '@link_name' expects a string literal"
+    );
+    assert_error(
+        "mod m; @deprecated(1, 2) fun f() {}",
+        "the attribute '@deprecated' expects no arguments or a string literal"
+    );
+    assert_error(
+        "mod m; @inline(1) fun f() {}",
+        "the attribute '@inline' expects no arguments"
+    );
+}
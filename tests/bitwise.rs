@@ -0,0 +1,67 @@
+mod common;
+
+use common::*;
+
+#[test]
+fn operators() {
+    assert_ok(concat!(
+        "mod m; fun f(a u32, b u32, s u8): u32 {\n",
+        "    var x u32 = (a & b) | (a ^ ~b);\n",
+        "    x &= 0xFF; x |= 1; x ^= b;\n",
+        "    x <<= 2; x >>= s;\n",
+        "    return x << 1 >> s;\n",
+        "}\n"
+    ));
+}
+
+#[test]
+fn precedence() {
+    assert_ok(concat!(
+        "mod m; static_assert((1 | 2 & 3) == 3);\n",
+        "static_assert((6 ^ 3 & 1) == 7);\n",
+        "static_assert((1 << 2 + 1) == 8);\n",
+        "static_assert((0x80 >> 4 | 1) == 9);\n",
+        "static_assert(~0 as u8 == 255);\n"
+    ));
+}
+
+#[test]
+fn integers_only() {
+    assert_error(
+        "mod m; fun f(a f32, b f32): f32 { return a & b; }",
+        "the operator '&' cannot be used on values of type 'f32' and 'f32'"
+    );
+    assert_error(
+        "mod m; fun f(a bool): bool { return ~a; }",
+        "the bits of a value of type 'bool' cannot be inverted"
+    );
+    assert_error(
+        "mod m; fun f(a u8, b u16): u16 { return a | b; }",
+        "the operator '|' cannot be used on values of type 'u8' and 'u16'"
+    );
+    assert_error(
+        "mod m; fun f(a f64): f64 { return a << 1; }",
+        "a value of type 'f64' cannot be shifted"
+    );
+    assert_error(
+        "mod m; fun f(a f64) { var x f64 = a; x ^= a; }",
+        "the operator '^=' cannot be used on values of type 'f64' and 'f64'"
+    );
+}
+
+#[test]
+fn shift_amounts() {
+    assert_error(
+        "mod m; fun f(a u32, s s8): u32 { return a << s; }",
+        "the amount to shift by must be an unsigned integer, but is of type 's8'"
+    );
+    assert_error(
+        "mod m; fun f(a u32): u32 { return a >> -1; }",
+        "the amount to shift by must not be negative"
+    );
+    assert_error(
+        "mod m; fun f(a u16): u16 { return a << 16; }",
+        "a value of type 'u16' cannot be shifted by 16 bits, since it only has 16 bits"
+    );
+    assert_ok("mod m; fun f(a u16): u16 { return a << 15; }");
+}
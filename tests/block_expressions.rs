@@ -0,0 +1,65 @@
+mod common;
+
+use common::*;
+
+#[test]
+fn if_expressions() {
+    assert_ok(concat!(
+        "mod m; fun max(a u32, b u32): u32 {\n",
+        "    const m u32 = if a > b { a } else { b };\n",
+        "    const n u32 = if a > b { a } else if a == b { 0 } else { b };\n",
+        "    const r u32 = if a > b { a } else { return 0; };\n",
+        "    return if a > b { m } else { n + r };\n",
+        "}\n"
+    ));
+}
+
+#[test]
+fn block_expressions() {
+    assert_ok(concat!(
+        "mod m; ext fun release(h u32);\n",
+        "fun f(a u32, b u32): u32 {\n",
+        "    const o u32 = { const y u32 = 2; y * a };\n",
+        "    const t u32 = {\n",
+        "        defer release(1);\n",
+        "        a + b\n",
+        "    };\n",
+        "    return o + t;\n",
+        "}\n"
+    ));
+}
+
+#[test]
+fn unused_values() {
+    assert_ok(concat!(
+        "mod m; ext fun release(h u32);\n",
+        "fun f(a u32, b u32) {\n",
+        "    if a > b { release(a) } else { release(b) }\n",
+        "    if a > b { release(a) }\n",
+        "}\n"
+    ));
+}
+
+#[test]
+fn missing_else() {
+    assert_error(
+        "mod m; fun f(a u32, b u32) { const p u32 = if a > b { a }; }",
+        "the 'if' needs an 'else' branch to produce a value of type 'u32'"
+    );
+}
+
+#[test]
+fn branch_types() {
+    assert_error(
+        "mod m; fun f(a u32, b u32) { const q u32 = if a > b { a } else { true }; }",
+        "expected a value of type 'u32', but got a value of type 'bool'"
+    );
+    assert_error(
+        "mod m; fun f(a u32, b u32) { const s u8 = if a > b { 1 } else { 300 }; }",
+        "the value 300 does not fit into the type 'u8'"
+    );
+    assert_error(
+        "mod m; fun f() { const s u8 = { const y u16 = 2; y }; }",
+        "expected a value of type 'u8', but got a value of type 'u16'"
+    );
+}
@@ -0,0 +1,64 @@
+mod common;
+
+use common::*;
+
+#[test]
+fn character_literals() {
+    assert_ok(concat!(
+        "mod m; static_assert('a' == 97);\n",
+        "static_assert('\\n' == 10);\n",
+        "static_assert('\\'' == 39);\n",
+        "static_assert('\\\\' == 92);\n",
+        "static_assert('\\x7F' == 127);\n",
+        "static_assert('\\u{1F600}' == 128512);\n",
+        "const c u8 = 'z';\n"
+    ));
+}
+
+#[test]
+fn character_types() {
+    assert_error(
+        "mod m; const c u8 = '\\u{3B1}';",
+        "the character '\\u{3b1}' (945) does not fit into the type 'u8'"
+    );
+    assert_error(
+        "mod m; const c u16 = 'a'; const d u8 = c;",
+        "expected a value of type 'u8', but got a value of type 'u16'"
+    );
+}
+
+#[test]
+fn malformed_characters() {
+    assert_error("mod m; const c u8 = '';", "character literal is empty");
+    assert_error(
+        "mod m; const c u8 = 'ab';",
+        "character literal contains more than one character"
+    );
+    assert_error("mod m; const c u8 = 'a;", "character literal is never closed");
+}
+
+#[test]
+fn hex_escapes() {
+    const MESSAGE: &str = "'\\x' must be followed by two hexadecimal digits of at most 7F";
+    assert_error("mod m; const c u8 = '\\x7';", MESSAGE);
+    assert_error("mod m; const c u8 = '\\x';", MESSAGE);
+    assert_error("mod m; const c u8 = '\\x80';", MESSAGE);
+    assert_error("mod m; const s *const u8 = c\"\\xG1\";", MESSAGE);
+    assert_ok("mod m; const s *const u8 = c\"\\x41\\x7f\";");
+}
+
+#[test]
+fn invalid_escapes() {
+    assert_error(
+        "mod m; const c u8 = '\\q';",
+        "'\\q' is not a valid escape sequence"
+    );
+    assert_error(
+        "mod m; const s *const u8 = c\"a\\kb\";",
+        "'\\k' is not a valid escape sequence"
+    );
+    assert_error(
+        "mod m; const c u32 = '\\u{110000}';",
+        "invalid unicode codepoint sequence"
+    );
+}
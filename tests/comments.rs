@@ -0,0 +1,106 @@
+mod common;
+
+use common::*;
+use tolac::{AstNode, Compiler, NodeType, NodeValue};
+
+fn declaration(c: &Compiler, path: &str) -> AstNode {
+    return c.symbols.symbols().iter()
+        .find(|(p, _)| p.display(c) == path)
+        .map(|(_, s)| s.decl_node.clone())
+        .expect("should be declared");
+}
+
+fn is_documented(n: &AstNode) -> bool {
+    return n.children.iter().any(|c| c.t == NodeType::DocComment);
+}
+
+#[test]
+fn block_comments() {
+    assert_ok(concat!(
+        "mod m; /* a block comment */ fun f(): u32 {\n",
+        "    /* spanning\n",
+        "       lines /* and nested */ still inside */\n",
+        "    return 1 /* inline */ + 2;\n",
+        "}\n"
+    ));
+    assert_error("mod m; /* /* */ fun f() {}", "block comment is never closed");
+}
+
+#[test]
+fn line_comments_keep_their_meaning() {
+    assert_ok(concat!(
+        "mod m;\n",
+        "#[this used to be a line comment\n",
+        "fun f(a u32, p *u32): u32 { return a / *p; } # ends here ]#\n"
+    ));
+}
+
+#[test]
+fn documented_declarations() {
+    let mut c: Compiler = Compiler::new();
+    let errors: Vec<String> = check(&mut c, concat!(
+        "mod m;\n",
+        "## a point\n",
+        "## with two lines\n",
+        "struct Point(\n",
+        "    ## the horizontal position\n",
+        "    x u32,\n",
+        "    y u32\n",
+        ");\n",
+        "@inline\n",
+        "## documented after the attributes\n",
+        "fun f() {}\n",
+        "enum E u8 {\n",
+        "    ## the first variant\n",
+        "    A = 3,\n",
+        "    B\n",
+        "}\n"
+    ));
+    assert!(errors.is_empty(), "unexpected errors: {:#?}", errors);
+    let point: AstNode = declaration(&c, "m::Point");
+    assert!(point.documentation().is_some());
+    let fields: &AstNode = point.children.iter()
+        .filter(|c| c.t == NodeType::ArgumentList)
+        .nth(1).expect("should have fields");
+    assert!(is_documented(&fields.children[0]));
+    assert!(!is_documented(&fields.children[1]));
+    assert!(declaration(&c, "m::f").documentation().is_some());
+    let variant: AstNode = declaration(&c, "m::E::A");
+    assert!(is_documented(&variant));
+    assert!(variant.children.iter().any(|c| c.t == NodeType::IntegerLiteral
+        && matches!(c.value, NodeValue::String(_))
+    ));
+}
+
+#[test]
+fn misplaced_documentation() {
+    const MESSAGE: &str = "documentation comments must be placed before a declaration";
+    assert_error("mod m; fun f() { ## nothing\n f(); }", MESSAGE);
+    assert_error("mod m; fun f(): u32 { return 1 + ## two\n 2; }", MESSAGE);
+    assert_error("mod m; fun f() {}\n## at the end\n", MESSAGE);
+    assert_error("mod m; struct S(a u32 ## after\n);", MESSAGE);
+}
+
+#[test]
+fn documented_discriminants() {
+    assert_error(
+        concat!(
+            "mod m; enum E u8 {\n",
+            "    ## starts at the end\n",
+            "    A = 255,\n",
+            "    B\n",
+            "}\n"
+        ),
+        "the discriminant of 'm::E::B' (256) does not fit into the backing type 'u8'"
+    );
+}
+
+#[test]
+fn documentation_while_backtracking() {
+    let errors: Vec<String> = errors(
+        "mod m; fun f(a [2]u32): u32 { return a[## index\n 0]; }\n## g\nfun g() {}"
+    );
+    assert_eq!(errors, vec!(String::from(
+        "documentation comments must be placed before a declaration"
+    )));
+}
@@ -0,0 +1,26 @@
+#![allow(dead_code)]
+
+use tolac::Compiler;
+
+pub fn check(c: &mut Compiler, source: &str) -> Vec<String> {
+    c.parse("test.tola", String::from(source));
+    c.check_types();
+    c.errors().iter().map(|e| e.reason.clone()).collect()
+}
+
+pub fn errors(source: &str) -> Vec<String> {
+    check(&mut Compiler::new(), source)
+}
+
+pub fn assert_ok(source: &str) {
+    let errors: Vec<String> = errors(source);
+    assert!(errors.is_empty(), "unexpected errors: {:#?}", errors);
+}
+
+pub fn assert_error(source: &str, expected: &str) {
+    let errors: Vec<String> = errors(source);
+    assert!(
+        errors.iter().any(|e| e == expected),
+        "expected the error '{}', got: {:#?}", expected, errors
+    );
+}
@@ -0,0 +1,115 @@
+mod common;
+
+use common::*;
+use tolac::Compiler;
+
+// the symbols of module 'm' that are declared with the given properties
+fn declared(properties: &[(&str, &str)], source: &str) -> Vec<String> {
+    let mut c: Compiler = Compiler::new();
+    for (name, value) in properties {
+        c.define(name, value);
+    }
+    let errors: Vec<String> = check(&mut c, source);
+    assert!(errors.is_empty(), "unexpected errors: {:#?}", errors);
+    let mut declared: Vec<String> = c.symbols.symbols().keys()
+        .map(|p| p.display(&c))
+        .filter(|p| p.starts_with("m::"))
+        .collect();
+    declared.sort();
+    return declared;
+}
+
+const BRANCHES: &str = concat!(
+    "mod m;\n",
+    "when os == \"linux\" && pointer_width == \"64\" {\n",
+    "    fun linux() {}\n",
+    "} else when os == \"windows\" {\n",
+    "    fun windows() {}\n",
+    "} else {\n",
+    "    fun other() {}\n",
+    "}\n"
+);
+
+#[test]
+fn branches() {
+    assert_eq!(
+        declared(&[("os", "linux"), ("pointer_width", "64")], BRANCHES),
+        ["m::linux"]
+    );
+    assert_eq!(
+        declared(&[("os", "linux"), ("pointer_width", "32")], BRANCHES),
+        ["m::other"]
+    );
+    assert_eq!(declared(&[("os", "windows")], BRANCHES), ["m::windows"]);
+}
+
+#[test]
+fn pointer_width() {
+    let source: &str = concat!(
+        "mod m;\n",
+        "when pointer_width == \"32\" {\n",
+        "    static_assert(sizeof usize == 4 && sizeof *u8 == 4);\n",
+        "    static_assert(sizeof []u8 == 8 && sizeof ?*u8 == 4);\n",
+        "    const max usize = 4294967295;\n",
+        "} else {\n",
+        "    static_assert(sizeof usize == 8 && sizeof []u8 == 16);\n",
+        "}\n"
+    );
+    assert_eq!(declared(&[("pointer_width", "32")], source), ["m::max"]);
+    assert_eq!(declared(&[("pointer_width", "64")], source), Vec::<String>::new());
+    let mut c: Compiler = Compiler::new();
+    c.define("pointer_width", "32");
+    assert_eq!(
+        check(&mut c, "mod m; const max usize = 4294967296;"),
+        ["the value 4294967296 does not fit into the type 'usize'"]
+    );
+    let mut c: Compiler = Compiler::new();
+    c.define("pointer_width", "12");
+    assert_eq!(
+        check(&mut c, "mod m;"),
+        ["the pointer width '12' is not supported (expected 16, 32 or 64)"]
+    );
+}
+
+#[test]
+fn flags() {
+    let source: &str = concat!(
+        "mod m;\n",
+        "when DEBUG { fun debug() {} }\n",
+        "when !DEBUG || LEVEL != \"2\" {\n",
+        "    fun release() {}\n",
+        "    when true { fun nested() {} }\n",
+        "}\n"
+    );
+    assert_eq!(declared(&[], source), ["m::nested", "m::release"]);
+    assert_eq!(
+        declared(&[("DEBUG", "")], source),
+        ["m::debug", "m::nested", "m::release"]
+    );
+    assert_eq!(declared(&[("DEBUG", ""), ("LEVEL", "2")], source), ["m::debug"]);
+}
+
+#[test]
+fn no_duplicate_symbols() {
+    assert_ok(concat!(
+        "mod m;\n",
+        "when foo { fun h(): u32 { return 1; } }\n",
+        "when !foo { fun h(): u32 { return 2; } }\n",
+        "fun k(): u32 { return h(); }\n"
+    ));
+    assert_error(
+        "mod m; fun h() {} when true { fun h() {} }",
+        "the symbol 'm::h' is declared more than once"
+    );
+}
+
+#[test]
+fn invalid_conditions() {
+    let message: &str = concat!(
+        "conditions of a 'when' may only consist of properties, literals ",
+        "and the operators '==', '!=', '!', '&&' and '||'"
+    );
+    assert_error("mod m; when 1 + 2 { fun h() {} }", message);
+    assert_error("mod m; when x::y { fun h() {} }", message);
+    assert_error("mod m; fun k() { when debug { } }", "unexpected 'when' after '{'");
+}
@@ -0,0 +1,116 @@
+mod common;
+
+use common::*;
+use tolac::{AstNode, Compiler, NodeType};
+
+const EXTERNAL: &str = "mod m; ext fun acquire(): u32; ext fun release(h u32);\n";
+
+// the calls to 'release' and the exits of the lowered function body,
+// in the order in which they appear
+fn trace(body: &str) -> Vec<String> {
+    let source: String = format!("{}{}", EXTERNAL, body);
+    let mut c: Compiler = Compiler::new();
+    let errors: Vec<String> = check(&mut c, &source);
+    assert!(errors.is_empty(), "unexpected errors: {:#?}", errors);
+    let function: AstNode = c.symbols.symbols().iter()
+        .find(|(p, _)| p.display(&c) == "m::f")
+        .map(|(_, s)| s.decl_node.clone())
+        .expect("should be declared");
+    let mut trace: Vec<String> = Vec::new();
+    collect(&function, &source, &mut trace);
+    return trace;
+}
+
+fn collect(n: &AstNode, source: &str, trace: &mut Vec<String>) {
+    let text: &str = &source[n.source.start..n.source.end];
+    match n.t {
+        NodeType::Call if text.starts_with("release") => {
+            trace.push(String::from(text));
+        }
+        NodeType::Return => trace.push(String::from("return")),
+        NodeType::Break => trace.push(String::from("break")),
+        NodeType::Continue => trace.push(String::from("continue")),
+        _ => {}
+    }
+    for child in &n.children {
+        collect(child, source, trace);
+    }
+}
+
+#[test]
+fn fallthrough() {
+    assert_eq!(
+        trace(concat!(
+            "fun f() {\n",
+            "    defer release(1);\n",
+            "    defer { release(2); }\n",
+            "    acquire();\n",
+            "}\n"
+        )),
+        ["release(2)", "release(1)"]
+    );
+}
+
+#[test]
+fn early_exits() {
+    assert_eq!(
+        trace(concat!(
+            "fun f(n u32): u32 {\n",
+            "    const h u32 = acquire();\n",
+            "    defer release(h);\n",
+            "    if n == 0 { return 0; }\n",
+            "    defer release(2);\n",
+            "    return h;\n",
+            "}\n"
+        )),
+        ["release(h)", "return", "release(2)", "release(h)", "return"]
+    );
+}
+
+#[test]
+fn loops() {
+    assert_eq!(
+        trace(concat!(
+            "fun f(n u32) {\n",
+            "    while n > 0 {\n",
+            "        defer release(1);\n",
+            "        if n == 5 { break; }\n",
+            "        if n == 6 { continue; }\n",
+            "        n -= 1;\n",
+            "    }\n",
+            "}\n"
+        )),
+        ["release(1)", "break", "release(1)", "continue", "release(1)"]
+    );
+}
+
+#[test]
+fn outer_defers_in_loops() {
+    // leaving the loop does not leave the enclosing block
+    assert_eq!(
+        trace(concat!(
+            "fun f(n u32): u32 {\n",
+            "    defer release(1);\n",
+            "    while n > 0 {\n",
+            "        if n == 5 { break; }\n",
+            "        if n == 6 { return 1; }\n",
+            "    }\n",
+            "    return 0;\n",
+            "}\n"
+        )),
+        ["break", "release(1)", "return", "release(1)", "return"]
+    );
+}
+
+#[test]
+fn leaving_deferred_code() {
+    assert_error(
+        "mod m; fun f(): u32 { defer return 5; return 1; }",
+        "'return' cannot be used to leave deferred code"
+    );
+    assert_error(
+        "mod m; fun f() { while true { defer { continue; } } }",
+        "'continue' cannot be used to leave deferred code"
+    );
+    assert_ok("mod m; fun f() { defer { while true { break; } } }");
+}
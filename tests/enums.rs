@@ -0,0 +1,79 @@
+mod common;
+
+use common::*;
+
+#[test]
+fn variants_are_paths() {
+    assert_ok(concat!(
+        "mod m; enum Color u8 { Red, Green = 5, Blue }\n",
+        "fun f(): Color { return Color::Blue; }\n",
+        "fun g(): bool { return f() == m::Color::Red; }"
+    ));
+}
+
+#[test]
+fn default_backing_type() {
+    assert_ok("mod m; enum E { A = 4294967295 }");
+}
+
+#[test]
+fn duplicate_discriminant() {
+    assert_error(
+        "mod m; enum Color u8 { Red = 5, Green = 4, Blue }",
+        concat!(
+            "the variant 'm::Color::Blue' has the same discriminant (5) ",
+            "as the variant 'm::Color::Red'"
+        )
+    );
+}
+
+#[test]
+fn discriminant_out_of_range() {
+    assert_error(
+        "mod m; enum Color u8 { Red = 256 }",
+        "the discriminant of 'm::Color::Red' (256) does not fit into the backing type 'u8'"
+    );
+    assert_error(
+        "mod m; enum Sign s8 { Minus = -129 }",
+        "the discriminant of 'm::Sign::Minus' (-129) does not fit into the backing type 's8'"
+    );
+}
+
+#[test]
+fn constant_discriminants() {
+    assert_ok(concat!(
+        "mod m; const BASE u8 = 16;\n",
+        "enum Flag u8 { A = BASE, B = BASE * 2, C = -(-4) + 1 }"
+    ));
+    assert_error(
+        "mod m; const BASE u8 = 5; enum Color u8 { Red = BASE, Green = 5 }",
+        concat!(
+            "the variant 'm::Color::Green' has the same discriminant (5) ",
+            "as the variant 'm::Color::Red'"
+        )
+    );
+    assert_error(
+        "mod m; var base u8 = 5; enum Color u8 { Red = base }",
+        "the value of this expression is not known at compile time"
+    );
+    assert_error(
+        "mod m; enum Color u8 { Red = true }",
+        "enum discriminants must be integers"
+    );
+}
+
+#[test]
+fn backing_type_must_be_integer() {
+    assert_error(
+        "mod m; enum E bool { A }",
+        "the backing type of the enum 'm::E' must be an integer type, but is 'bool'"
+    );
+}
+
+#[test]
+fn enums_are_not_integers() {
+    assert_error(
+        "mod m; enum E u8 { A } fun f(): u8 { return E::A; }",
+        "expected a value of type 'u8', but got a value of type 'm::E'"
+    );
+}
@@ -0,0 +1,86 @@
+mod common;
+
+use common::*;
+
+#[test]
+fn ranges() {
+    assert_ok(concat!(
+        "mod m; fun f(n u32): u32 {\n",
+        "    var sum u32 = 0;\n",
+        "    for i in 0..n { sum += i; }\n",
+        "    for i in 1..=n { sum += i; }\n",
+        "    for i in 0..n step 2 {\n",
+        "        if i == 4 { continue; }\n",
+        "        if i > 10 { break; }\n",
+        "        sum += i;\n",
+        "    }\n",
+        "    return sum;\n",
+        "}\n"
+    ));
+}
+
+#[test]
+fn variable_types() {
+    assert_ok("mod m; fun f() { for i in 0..10 { const x usize = i; } }");
+    assert_ok("mod m; fun f(n u8) { for i in 0..n { const x u8 = i; } }");
+    assert_error(
+        "mod m; fun f(n u8) { for i in 0..n { const x u16 = i; } }",
+        "expected a value of type 'u16', but got a value of type 'u8'"
+    );
+}
+
+#[test]
+fn invalid_bounds() {
+    assert_error(
+        "mod m; fun f(a u8, b u16) { for i in a..b {} }",
+        "the bounds of a range must be integers of the same type, but are of type 'u8' and 'u16'"
+    );
+    assert_error(
+        "mod m; fun f(a f32) { for i in 0.0..a {} }",
+        "the bounds of a range must be integers of the same type, but are of type '<float>' and 'f32'"
+    );
+    assert_error(
+        "mod m; fun f(n u32) { for i in 0..n step 0 {} }",
+        "the step of a range must be positive"
+    );
+    assert_error(
+        "mod m; fun f(n s32) { for i in 0..n step -1 {} }",
+        "the step of a range must be positive"
+    );
+    assert_error(
+        "mod m; fun f(n u32) { const Z u32 = 0; for i in 0..n step Z {} }",
+        "the step of a range must be positive"
+    );
+    assert_error(
+        "mod m; const Z s32 = 2; fun f(n s32) { for i in 0..n step 1 - Z {} }",
+        "the step of a range must be positive"
+    );
+    assert_ok(concat!(
+        "mod m; const S u32 = 2;\n",
+        "fun f(n u32, k u32) { for i in 0..n step S * 2 {} for j in 0..n step k {} }"
+    ));
+}
+
+#[test]
+fn arrays_and_slices() {
+    assert_ok(concat!(
+        "mod m; fun f(a [4]u16, s []const u16): u16 {\n",
+        "    var sum u16 = 0;\n",
+        "    for x in a { sum += x; }\n",
+        "    for x in s { if x == 0 { break; } sum += x; }\n",
+        "    return sum;\n",
+        "}\n"
+    ));
+    assert_error(
+        "mod m; fun f(a [4]u16) { for x in a { x = 1; } }",
+        "the constant 'x' cannot be modified"
+    );
+}
+
+#[test]
+fn only_sequences() {
+    assert_error(
+        "mod m; fun f(n u32) { for x in n {} }",
+        "a value of type 'u32' cannot be iterated over"
+    );
+}
@@ -0,0 +1,161 @@
+mod common;
+
+use common::*;
+
+#[test]
+fn declarations() {
+    assert_ok(concat!(
+        "mod m;\n",
+        "pub interface Shape { fun area(s *const Shape): f32; }\n",
+        "interface Named {\n",
+        "    fun name(n *const Named): u32;\n",
+        "    fun rename(n *Named, name u32);\n",
+        "}\n",
+        "struct Square(w f32): Shape, m::Named;\n",
+        "fun Square::area(s *const Square): f32 { return s.w * s.w; }\n",
+        "fun Square::name(s *const Square): u32 { return 4; }\n",
+        "fun Square::rename(s *Square, name u32) {}\n"
+    ));
+}
+
+#[test]
+fn functions_have_no_bodies() {
+    assert_error(
+        "mod m; interface Shape { fun area(s *Shape): f32 { return 1.0; } }",
+        "unexpected '{' after 'f32'"
+    );
+}
+
+#[test]
+fn listed_interfaces_must_be_interfaces() {
+    assert_error(
+        "mod m; struct D(r f32); struct C(r f32): D;",
+        "'m::D' is not an interface"
+    );
+    assert_error(
+        "mod m; struct C(r f32): Nope;",
+        "'Nope' is not an interface"
+    );
+}
+
+#[test]
+fn missing_interface_name() {
+    assert_error(
+        "mod m; interface Shape { fun area(s *Shape): f32; } struct C(r f32): Shape, ;",
+        "unexpected ';' after ','"
+    );
+}
+
+const SHAPE: &str = "mod m; interface Shape { fun area(s *const Shape): f32; }\n";
+
+#[test]
+fn conforming_struct() {
+    assert_ok(&format!(
+        "{}{}{}",
+        SHAPE,
+        "struct Circle(r f32): Shape;\n",
+        "fun Circle::area(c *const Circle): f32 { return c.r * c.r; }\n"
+    ));
+}
+
+#[test]
+fn missing_implementation() {
+    assert_error(
+        &format!("{}{}", SHAPE, "struct Circle(r f32): Shape;"),
+        concat!(
+            "the struct 'm::Circle' does not implement 'm::Circle::area', ",
+            "which is required by the interface 'm::Shape'"
+        )
+    );
+}
+
+#[test]
+fn mismatched_signature() {
+    assert_error(
+        &format!(
+            "{}{}{}",
+            SHAPE,
+            "struct Circle(r f32): Shape;\n",
+            "fun Circle::area(c *const Circle): f64 { return 1.0; }\n"
+        ),
+        concat!(
+            "'m::Circle::area' does not match the signature required by the ",
+            "interface 'm::Shape' (expected 'fun(*const m::Circle): f32', ",
+            "found 'fun(*const m::Circle): f64')"
+        )
+    );
+}
+
+#[test]
+fn interface_listed_twice() {
+    assert_error(
+        &format!(
+            "{}{}{}",
+            SHAPE,
+            "struct Circle(r f32): Shape, Shape;\n",
+            "fun Circle::area(c *const Circle): f32 { return c.r; }\n"
+        ),
+        "the interface 'm::Shape' is listed more than once"
+    );
+}
+
+#[test]
+fn receiver_is_required() {
+    assert_error(
+        "mod m; interface Shape { fun area(s f32): f32; }",
+        "the first argument of 'm::Shape::area' must be a pointer or reference to 'm::Shape'"
+    );
+}
+
+#[test]
+fn no_template_arguments() {
+    assert_error(
+        "mod m; interface Shape { fun area[T](s *Shape): f32; }",
+        "'m::Shape::area' is called dynamically and may therefore not specify any template arguments"
+    );
+}
+
+#[test]
+fn function_declared_twice() {
+    assert_error(
+        "mod m; interface Shape { fun area(s *Shape): f32; fun area(s *Shape): f32; }",
+        "the function 'm::Shape::area' is declared more than once"
+    );
+}
+
+#[test]
+fn pointers_convert_to_implemented_interfaces() {
+    assert_ok(&format!(
+        "{}{}{}{}",
+        SHAPE,
+        "struct Circle(r f32): Shape;\n",
+        "fun Circle::area(c *const Circle): f32 { return c.r * c.r; }\n",
+        "fun f(c *const Circle): *const Shape { return c; }\n"
+    ));
+    assert_error(
+        "mod m; interface Shape { fun area(s *const Shape): f32; } \
+            struct Square(w f32); fun f(s *const Square): *const Shape { return s; }",
+        "expected a value of type '*const m::Shape', but got a value of type '*const m::Square'"
+    );
+}
+
+#[test]
+fn interfaces_are_only_pointees() {
+    assert_ok(&format!(
+        "{}{}",
+        SHAPE,
+        "fun f(s *const Shape, r &Shape, n ?*Shape): f32 { return s.area(); }"
+    ));
+    let by_value: &str = concat!(
+        "the interface 'm::Shape' can only be used ",
+        "behind a pointer or a reference"
+    );
+    assert_error(&format!("{}fun f(s Shape) {{}}", SHAPE), by_value);
+    assert_error(&format!("{}fun f() {{ var x Shape; }}", SHAPE), by_value);
+    assert_error(&format!("{}fun f(): usize {{ return sizeof Shape; }}", SHAPE), by_value);
+    assert_error(&format!("{}fun f(s *[3]Shape) {{}}", SHAPE), by_value);
+    assert_error(
+        &format!("{}fun f(s *const Shape): f32 {{ return s.r; }}", SHAPE),
+        "the type 'm::Shape' has no field 'r'"
+    );
+}
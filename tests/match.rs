@@ -0,0 +1,97 @@
+mod common;
+
+use common::*;
+
+const COLOR: &str = "mod m; enum Color { Red, Green, Blue }\n";
+
+#[test]
+fn exhaustive_matches() {
+    assert_ok(&format!(
+        "{}{}",
+        COLOR,
+        concat!(
+            "fun f(c Color, x u8, b bool) {\n",
+            "    match c { Color::Red, Color::Green => {} Color::Blue => {} }\n",
+            "    match x { 0..10 => {} 10..=255 => {} }\n",
+            "    match b { true => {} false => {} }\n",
+            "    match x { 7 => {} _ => {} }\n",
+            "}\n"
+        )
+    ));
+}
+
+#[test]
+fn missing_variants() {
+    assert_error(
+        &format!("{}fun f(c Color) {{ match c {{ Color::Red => {{}} }} }}", COLOR),
+        concat!(
+            "the match does not handle the variants 'm::Color::Green', ",
+            "'m::Color::Blue' (add the missing patterns or a '_' arm)"
+        )
+    );
+}
+
+#[test]
+fn missing_values() {
+    assert_error(
+        "mod m; fun f(x u8) { match x { 0..=254 => {} } }",
+        concat!(
+            "the match does not handle the value 255 ",
+            "(add the missing patterns or a '_' arm)"
+        )
+    );
+    assert_error(
+        "mod m; fun f(b bool) { match b { true => {} } }",
+        concat!(
+            "the match does not handle the value false ",
+            "(add the missing patterns or a '_' arm)"
+        )
+    );
+}
+
+#[test]
+fn unreachable_patterns() {
+    assert_error(
+        &format!(
+            "{}fun f(c Color) {{ match c {{ Color::Red => {{}} {} }} }}",
+            COLOR,
+            "Color::Red => {} _ => {}"
+        ),
+        "the pattern is unreachable, as all of its values are matched by earlier patterns"
+    );
+    assert_error(
+        "mod m; fun f(x u8) { match x { 0..=9 => {} 5 => {} _ => {} } }",
+        "the pattern is unreachable, as all of its values are matched by earlier patterns"
+    );
+    assert_error(
+        "mod m; fun f(x u8) { match x { _ => {} 5 => {} } }",
+        "the pattern is unreachable, as all of its values are matched by earlier patterns"
+    );
+}
+
+#[test]
+fn invalid_patterns() {
+    assert_error(
+        "mod m; fun f(x s32) { match x { 5..3 => {} _ => {} } }",
+        "the range pattern does not contain any values"
+    );
+    assert_error(
+        "mod m; fun f(x u8) { match x { 300 => {} _ => {} } }",
+        "the value 300 does not fit into the type 'u8'"
+    );
+    assert_error(
+        &format!(
+            "{}fun f(c Color) {{ match c {{ Color::Red..Color::Blue => {{}} _ => {{}} }} }}",
+            COLOR
+        ),
+        "range patterns cannot be used on values of type 'm::Color'"
+    );
+}
+
+#[test]
+fn invalid_values() {
+    assert_error(
+        "mod m; fun f(x f32) { match x { _ => {} } }",
+        "a value of type 'f32' cannot be matched on"
+    );
+}
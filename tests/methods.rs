@@ -0,0 +1,162 @@
+mod common;
+
+use common::*;
+
+const COUNTER: &str = concat!(
+    "mod m; struct Counter(n u32);\n",
+    "fun Counter::get(c *const Counter): u32 { return c.n; }\n",
+    "fun Counter::add(c *Counter, v u32) { c.n += v; }\n",
+    "fun Counter::copy(c Counter): Counter { return c; }\n",
+    "fun make(): Counter { return Counter { n = 0 }; }\n"
+);
+
+fn counter(body: &str) -> String {
+    return format!("{}{}", COUNTER, body);
+}
+
+#[test]
+fn receivers() {
+    assert_ok(&counter(concat!(
+        "fun f(p *Counter, q *const Counter): u32 {\n",
+        "    var c Counter = make();\n",
+        "    c.add(1);\n",
+        "    p.add(2);\n",
+        "    const d Counter = c.copy();\n",
+        "    return c.get() + d.get() + p.get() + q.get() + make().get();\n",
+        "}\n"
+    )));
+}
+
+#[test]
+fn reference_receivers() {
+    assert_ok(&counter(concat!(
+        "fun Counter::peek(c &const Counter): u32 { return c.n; }\n",
+        "fun f(r &Counter, p *Counter, q *const Counter): u32 {\n",
+        "    r.add(1);\n",
+        "    return r.get() + p.peek() + q.peek() + r.peek();\n",
+        "}\n"
+    )));
+    assert_error(
+        &counter("fun f(r &const Counter) { r.add(1); }"),
+        "expected a value of type '*m::Counter', but got a value of type '*const m::Counter'"
+    );
+}
+
+#[test]
+fn constant_receivers() {
+    assert_error(
+        &counter("fun f() { const c Counter = make(); c.add(1); }"),
+        "expected a value of type '*m::Counter', but got a value of type '*const m::Counter'"
+    );
+    assert_error(
+        &counter("fun f(q *const Counter) { q.add(1); }"),
+        "expected a value of type '*m::Counter', but got a value of type '*const m::Counter'"
+    );
+}
+
+#[test]
+fn temporary_receivers() {
+    assert_error(
+        &counter("fun f() { make().add(1); }"),
+        "expected a value of type '*m::Counter', but got a value of type '*const m::Counter'"
+    );
+}
+
+#[test]
+fn unknown_methods() {
+    assert_error(
+        &counter("fun f(c Counter): u32 { return c.nope(); }"),
+        "the type 'm::Counter' has no function 'nope'"
+    );
+}
+
+#[test]
+fn unknown_fields() {
+    assert_error(
+        "mod m; fun f(x u8): u8 { return x.foo; }",
+        "the type 'u8' has no field 'foo'"
+    );
+    assert_error(
+        "mod m; fun f(x [3]u8): usize { return x.length; }",
+        "the type '[3]u8' has no field 'length'"
+    );
+    assert_error(
+        "mod m; fun f(x *u8): u8 { return x.foo; }",
+        "the type 'u8' has no field 'foo'"
+    );
+    assert_error(
+        &counter("fun f(c Counter): u32 { return c.m; }"),
+        "the struct 'm::Counter' has no field 'm'"
+    );
+    assert_eq!(
+        errors("mod m; fun f(): u8 { return y.foo; }"),
+        vec!("the symbol 'y' does not exist")
+    );
+}
+
+#[test]
+fn arguments_are_checked() {
+    assert_error(
+        &counter("fun f(c *Counter) { c.add(true); }"),
+        "expected a value of type 'u32', but got a value of type 'bool'"
+    );
+    assert_error(
+        &counter("fun f(c *Counter) { c.add(); }"),
+        "'m::Counter::add' expects 2 arguments, but got 1"
+    );
+}
+
+#[test]
+fn constants_cannot_be_assigned() {
+    assert_error(
+        "mod m; fun f() { const x u32 = 1; x = 2; }",
+        "the constant 'x' cannot be modified"
+    );
+    assert_error(
+        "mod m; const g u32 = 1; fun f() { g += 2; }",
+        "the constant 'm::g' cannot be modified"
+    );
+    assert_error(
+        "mod m; struct P(x u32); fun f() { const p P = P { x = 1 }; p.x = 2; }",
+        "the constant 'p' cannot be modified"
+    );
+    assert_error(
+        "mod m; fun f() { const (a, b) = (1, 2); a = 3; }",
+        "the constant 'a' cannot be modified"
+    );
+    assert_ok("mod m; var g u32 = 1; fun f(x u32) { var y u32 = x; y = 2; g = y; }");
+}
+
+#[test]
+fn pointers_to_constants() {
+    assert_error(
+        "mod m; fun f(p *const u32) { *p = 1; }",
+        "values behind a constant pointer cannot be modified"
+    );
+    assert_error(
+        "mod m; fun f() { const x u32 = 1; var p *u32 = &x; }",
+        "expected a value of type '*u32', but got a value of type '*const u32'"
+    );
+    assert_ok("mod m; fun f() { var x u32 = 1; const p *u32 = &x; *p = 2; }");
+}
+
+#[test]
+fn temporaries_cannot_be_assigned() {
+    assert_error(
+        "mod m; fun g(): u32 { return 1; } fun f() { g() = 2; }",
+        "temporary values cannot be modified"
+    );
+}
+
+#[test]
+fn template_bodies_are_checked() {
+    assert_error(
+        "mod m; fun f[T](x T): T { return y; }",
+        "the symbol 'y' does not exist"
+    );
+    assert_error(
+        "mod m; fun f[T](x T): u8 { const b bool = 5; return 0; }",
+        "expected a value of type 'bool', but got a value of type '<integer>'"
+    );
+    assert_ok("mod m; fun f[T](x T): T { const y T = x; return y; }");
+}
@@ -0,0 +1,115 @@
+mod common;
+
+use common::*;
+
+const MAY_BE_NULL: &str = concat!(
+    "a value of type '?*u32' may be null, ",
+    "so it needs to be compared to 'null' before it can be used"
+);
+
+#[test]
+fn null_literals() {
+    assert_ok("mod m; var g ?*u32 = null; fun f(p ?*u32) { p = null; }");
+    assert_error(
+        "mod m; var g *u32 = null;",
+        "expected a value of type '*u32', but got a value of type 'null'"
+    );
+    assert_ok("mod m; fun f(q *u32) { var r ?*u32 = q; }");
+    assert_error(
+        "mod m; fun f(q ?*u32) { var r *u32 = q; }",
+        "expected a value of type '*u32', but got a value of type '?*u32'"
+    );
+}
+
+#[test]
+fn unchecked_dereference() {
+    assert_error("mod m; fun f(p ?*u32): u32 { return *p; }", MAY_BE_NULL);
+}
+
+#[test]
+fn narrowing() {
+    assert_ok(concat!(
+        "mod m; struct Node(value u32, next ?*Node);\n",
+        "fun f(p ?*u32, n ?*Node): u32 {\n",
+        "    if p != null { const a u32 = *p; }\n",
+        "    if n != null && n.value > 0 { return n.value; }\n",
+        "    if n == null || n.value > 0 { return 1; }\n",
+        "    const h u32 = n.value;\n",
+        "    if !(p == null) { const b u32 = *p; }\n",
+        "    if p == null { return 0; }\n",
+        "    return *p;\n",
+        "}\n"
+    ));
+}
+
+#[test]
+fn assignments_undo_narrowing() {
+    assert_error(
+        "mod m; fun f(p ?*u32) { if p != null { p = null; const c u32 = *p; } }",
+        MAY_BE_NULL
+    );
+    assert_error(
+        concat!(
+            "mod m; fun f(p ?*u32): u32 {\n",
+            "    while p != null { p = null; }\n",
+            "    if p != null { loop { const b u32 = *p; p = null; } }\n",
+            "    return 0;\n",
+            "}\n"
+        ),
+        MAY_BE_NULL
+    );
+}
+
+#[test]
+fn addresses_undo_narrowing() {
+    assert_error(
+        concat!(
+            "mod m; fun s(q *?*u32) { *q = null; }\n",
+            "fun f(q ?*u32): u32 { if q != null { s(&q); return *q; } return 0; }"
+        ),
+        MAY_BE_NULL
+    );
+    assert_error(
+        concat!(
+            "mod m; fun s(q *?*u32) { *q = null; }\n",
+            "fun f(q ?*u32): u32 {\n",
+            "    if q == null { return 0; } else { s(&q); }\n",
+            "    return *q;\n",
+            "}\n"
+        ),
+        MAY_BE_NULL
+    );
+}
+
+#[test]
+fn pointers_are_never_null() {
+    assert_error(
+        "mod m; fun f(p *u8): bool { return p == null; }",
+        "the comparison is always false, since values of type '*u8' are never null"
+    );
+    assert_error(
+        "mod m; fun f(p *u8): bool { return null != p; }",
+        "the comparison is always true, since values of type '*u8' are never null"
+    );
+    assert_ok("mod m; fun f(p ?*u8): bool { return p == null; }");
+}
+
+#[test]
+fn constness() {
+    assert_error(
+        "mod m; fun f(q *u32) { var z ?*const u32 = q; var y *u32 = z; }",
+        "expected a value of type '*u32', but got a value of type '?*const u32'"
+    );
+}
+
+#[test]
+fn invalid_types() {
+    assert_error(
+        "mod m; fun f(a ?u32) {}",
+        "only pointers can be nullable, but 'u32' is not a pointer"
+    );
+    assert_error(
+        "mod m; fun f(b ??*u32) {}",
+        "only pointers can be nullable, but '?*u32' is not a pointer"
+    );
+}
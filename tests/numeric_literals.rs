@@ -0,0 +1,75 @@
+mod common;
+
+use common::*;
+
+#[test]
+fn prefixes_and_separators() {
+    assert_ok(concat!(
+        "mod m; static_assert(0x1F == 31);\n",
+        "static_assert(0b1010 == 10);\n",
+        "static_assert(0o17 == 15);\n",
+        "static_assert(1_000_000 == 1000000);\n",
+        "static_assert(0xFF_FF == 65535);\n"
+    ));
+}
+
+#[test]
+fn misplaced_separators() {
+    let misplaced: &str = "digit separators must be placed between two digits";
+    assert_error("mod m; const a u8 = 1_;", misplaced);
+    assert_error("mod m; const a u8 = 1__0;", misplaced);
+    assert_error("mod m; const a u8 = 0x_1;", misplaced);
+    assert_error("mod m; const a u8 = 1_u8;", misplaced);
+    assert_error("mod m; const a f32 = 1_.5;", misplaced);
+    assert_eq!(errors("mod m; const a u16 = 1__0_;"), vec!(misplaced));
+}
+
+#[test]
+fn float_exponents() {
+    assert_ok(concat!(
+        "mod m; static_assert(1e3 == 1000.0);\n",
+        "static_assert(2.5e-1 == 0.25);\n",
+        "static_assert(1_0.0E+1 == 100.0);\n"
+    ));
+}
+
+#[test]
+fn suffixes() {
+    assert_ok(concat!(
+        "mod m; const a u8 = 10u8; const b f32 = 2.0f32; const c f64 = 3f64;\n",
+        "const d usize = 0x10usize; const e s8 = -128s8;\n"
+    ));
+    assert_error(
+        "mod m; const a u16 = 10u8;",
+        "expected a value of type 'u16', but got a value of type 'u8'"
+    );
+}
+
+#[test]
+fn suffix_overflow() {
+    assert_error("mod m; const a u8 = 256u8;", "the value 256 does not fit into the type 'u8'");
+    assert_error("mod m; const a s8 = 128s8;", "the value 128 does not fit into the type 's8'");
+    assert_error("mod m; const a u8 = 300;", "the value 300 does not fit into the type 'u8'");
+}
+
+#[test]
+fn malformed_literals() {
+    assert_error("mod m; const a u8 = 0x;", "the literal has no digits");
+    assert_error(
+        "mod m; const a u8 = 0b102;",
+        "'2' is not a valid digit in a literal of base 2"
+    );
+    assert_error("mod m; const a u8 = 10q8;", "'q8' is not a valid literal suffix");
+    assert_error(
+        "mod m; const a f32 = 1.5u8;",
+        "the integer suffix 'u8' cannot be used on a float literal"
+    );
+    assert_error(
+        "mod m; const a f32 = 0x1.5;",
+        "float literals must be written in base 10"
+    );
+    assert_error(
+        "mod m; const a u64 = 0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_F;",
+        "integer literal is too large"
+    );
+}
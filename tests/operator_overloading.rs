@@ -0,0 +1,110 @@
+mod common;
+
+use common::*;
+use tolac::{AstNode, Compiler, NodeType};
+
+const FIXED: &str = concat!(
+    "mod m; use std::ops::*;\n",
+    "struct Fx(v s32): Add, Negate, Equal, Ordered;\n",
+    "fun Fx::add(a *const Fx, b Fx): Fx { return Fx { v = a.v + b.v }; }\n",
+    "fun Fx::negate(a *const Fx): Fx { return Fx { v = -a.v }; }\n",
+    "fun Fx::equals(a *const Fx, b Fx): bool { return a.v == b.v; }\n",
+    "fun Fx::compare(a *const Fx, b Fx): s32 { return a.v - b.v; }\n"
+);
+
+fn with_fixed(source: &str) -> String {
+    return format!("{}{}", FIXED, source);
+}
+
+fn find_return(n: &AstNode) -> Option<AstNode> {
+    if n.t == NodeType::Return { return Some(n.children[0].clone()); }
+    return n.children.iter().find_map(find_return);
+}
+
+// the node types the value returned by 'm::f' is made of, without the
+// operands of calls
+fn shape(n: &AstNode) -> String {
+    if n.t == NodeType::Call || n.children.is_empty() {
+        return format!("{:?}", n.t);
+    }
+    let children: Vec<String> = n.children.iter().map(shape).collect();
+    return format!("{:?}({})", n.t, children.join(", "));
+}
+
+fn returned_shape(function: &str) -> String {
+    let mut c: Compiler = Compiler::new();
+    let errors: Vec<String> = check(&mut c, &with_fixed(function));
+    assert!(errors.is_empty(), "unexpected errors: {:#?}", errors);
+    let function: AstNode = c.symbols.symbols().iter()
+        .find(|(p, _)| p.display(&c) == "m::f")
+        .map(|(_, s)| s.decl_node.clone())
+        .expect("should be declared");
+    return shape(&find_return(&function).expect("should return a value"));
+}
+
+#[test]
+fn arithmetic() {
+    assert_eq!(returned_shape("fun f(a Fx, b Fx): Fx { return a + b; }"), "Call");
+    assert_eq!(returned_shape("fun f(a Fx): Fx { return -a; }"), "Call");
+    assert_ok(&with_fixed("fun f(a Fx, b Fx): Fx { return a + b + -a; }"));
+}
+
+#[test]
+fn comparisons() {
+    assert_eq!(
+        returned_shape("fun f(a Fx, b Fx): bool { return a == b; }"),
+        "Call"
+    );
+    assert_eq!(
+        returned_shape("fun f(a Fx, b Fx): bool { return a != b; }"),
+        "LogicalNot(Call)"
+    );
+    assert_eq!(
+        returned_shape("fun f(a Fx, b Fx): bool { return a < b; }"),
+        "LessThan(Call, IntegerLiteral)"
+    );
+    assert_eq!(
+        returned_shape("fun f(a Fx, b Fx): bool { return a >= b; }"),
+        "GreaterThanEqual(Call, IntegerLiteral)"
+    );
+}
+
+#[test]
+fn missing_implementations() {
+    assert_error(
+        &with_fixed("fun f(a Fx, b Fx): Fx { return a * b; }"),
+        concat!(
+            "the operator '*' cannot be used on values of type 'm::Fx', ",
+            "since it does not implement the interface 'std::ops::Multiply'"
+        )
+    );
+    assert_error(
+        "mod m; struct P(v s32); fun f(p P): bool { return p == p; }",
+        concat!(
+            "the operator '==' cannot be used on values of type 'm::P', ",
+            "since it does not implement the interface 'std::ops::Equal'"
+        )
+    );
+    assert_error(
+        "mod m; struct P(v s32); fun f(p P): bool { return p < p; }",
+        concat!(
+            "the operator '<' cannot be used on values of type 'm::P', ",
+            "since it does not implement the interface 'std::ops::Ordered'"
+        )
+    );
+}
+
+#[test]
+fn operand_types() {
+    assert_error(
+        &with_fixed("fun f(a Fx): Fx { return a + 5; }"),
+        "expected a value of type 'm::Fx', but got a value of type '<integer>'"
+    );    assert_error(
+        &with_fixed("fun f(a Fx, x u8): Fx { return x + a; }"),
+        "the operator '+' cannot be used on values of type 'u8' and 'm::Fx'"
+    );
+    assert_error(
+        &with_fixed("fun f(a Fx, x u8): bool { return x < a; }"),
+        "the operator '<' cannot be used on values of type 'u8' and 'm::Fx'"
+    );
+}
@@ -0,0 +1,24 @@
+mod common;
+
+use common::*;
+
+#[test]
+fn alias_for_first_segment() {
+    assert_ok(concat!(
+        "mod a::b::c; pub struct S(x u8);\n",
+        "mod m; use a::b; struct T(s b::c::S);"
+    ));
+}
+
+#[test]
+fn declaration_in_current_module() {
+    assert_ok("mod m; struct A(x u8); struct B(a A);");
+}
+
+#[test]
+fn unknown_path() {
+    assert_error(
+        "mod m; fun f(a A): unit { return unit; }",
+        "the symbol 'A' does not exist"
+    );
+}
@@ -0,0 +1,122 @@
+mod common;
+
+use common::*;
+
+const SHAPE: &str = "mod m; enum Shape { Circle(f32), Rect(f32, f32), Empty }\n";
+
+fn with_shape(source: &str) -> String {
+    return format!("{}{}", SHAPE, source);
+}
+
+#[test]
+fn construction_and_match() {
+    assert_ok(&with_shape(concat!(
+        "fun area(s Shape): f32 {\n",
+        "    match s {\n",
+        "        Shape::Circle(r) => { return r * r * 3.14; }\n",
+        "        Shape::Rect(w, h) => { return w * h; }\n",
+        "        Shape::Empty => { return 0.0; }\n",
+        "    }\n",
+        "    return 0.0;\n",
+        "}\n",
+        "fun f(): f32 {\n",
+        "    const a Shape = Shape::Circle(1.0);\n",
+        "    const b Shape = Shape::Rect(1.0, 2.0);\n",
+        "    const c Shape = Shape::Empty;\n",
+        "    return area(a) + area(b) + area(c);\n",
+        "}\n"
+    )));
+}
+
+#[test]
+fn conditional_destructuring() {
+    assert_ok(&with_shape(concat!(
+        "fun f(s Shape): f32 {\n",
+        "    if s is Shape::Circle(r) { return r; }\n",
+        "    return 0.0;\n",
+        "}\n"
+    )));
+    assert_error(
+        &with_shape("enum Plain { A, B }\nfun f(s Shape) { if s is Plain::A { } }"),
+        "expected a value of type 'm::Shape', but got a value of type 'm::Plain'"
+    );
+}
+
+#[test]
+fn payload_counts() {
+    assert_error(
+        &with_shape("fun f() { const s Shape = Shape::Rect(1.0); }"),
+        "'m::Shape::Rect' carries 2 values, but got 1"
+    );
+    assert_error(
+        &with_shape("fun f() { const s Shape = Shape::Empty(1); }"),
+        "the variant 'm::Shape::Empty' does not carry any values"
+    );
+    assert_error(
+        &with_shape("fun f() { const s Shape = Shape::Circle; }"),
+        "the variant 'm::Shape::Circle' carries values, which need to be specified"
+    );
+    assert_error(
+        &with_shape("fun f() { const s Shape = Shape::Circle(1); }"),
+        "expected a value of type 'f32', but got a value of type '<integer>'"
+    );
+}
+
+#[test]
+fn pattern_bindings() {
+    assert_error(
+        &with_shape(concat!(
+            "fun f(s Shape) {\n",
+            "    match s { Shape::Rect(w, h) => { const z u32 = w; } _ => { } }\n",
+            "}\n"
+        )),
+        "expected a value of type 'u32', but got a value of type 'f32'"
+    );
+    assert_error(
+        &with_shape(concat!(
+            "fun f(s Shape) {\n",
+            "    match s { Shape::Circle(x, y) => { } _ => { } }\n",
+            "}\n"
+        )),
+        "'m::Shape::Circle' carries 1 value, but the pattern has 2"
+    );
+    assert_error(
+        &with_shape(concat!(
+            "fun f(s Shape) {\n",
+            "    match s { Shape::Circle(_), Shape::Rect(w, _) => { } _ => { } }\n",
+            "}\n"
+        )),
+        "patterns that bind values cannot be combined with other patterns"
+    );
+}
+
+#[test]
+fn exhaustiveness() {
+    assert_error(
+        &with_shape("fun f(s Shape) { match s { Shape::Rect(w, h) => { } } }"),
+        concat!(
+            "the match does not handle the variants 'm::Shape::Circle', ",
+            "'m::Shape::Empty' (add the missing patterns or a '_' arm)"
+        )
+    );
+}
+
+#[test]
+fn infinite_size() {
+    assert_error(
+        "mod m; enum List { Cons(u32, List), Nil }",
+        concat!(
+            "the enum 'm::List' has an infinite size, because it contains ",
+            "itself (m::List::Cons -> m::List); consider using a pointer instead"
+        )
+    );
+    assert_ok("mod m; enum List { Cons(u32, *List), Nil }");
+}
+
+#[test]
+fn no_equality() {
+    assert_error(
+        &with_shape("fun f(a Shape, b Shape): bool { return a == b; }"),
+        "the operator '==' cannot be used on values of type 'm::Shape' and 'm::Shape'"
+    );
+}
@@ -0,0 +1,66 @@
+mod common;
+
+use common::*;
+
+#[test]
+fn direct_recursion() {
+    assert_error(
+        "mod m; struct Node(value u32, next Node);",
+        concat!(
+            "the struct 'm::Node' has an infinite size, because it contains ",
+            "itself (m::Node.next -> m::Node); consider using a pointer instead"
+        )
+    );
+}
+
+#[test]
+fn recursion_through_pointer() {
+    assert_ok("mod m; struct Node(value u32, next *Node);");
+}
+
+#[test]
+fn recursion_through_template_argument() {
+    assert_error(
+        "mod m; struct Opt[T](v T); struct Node(next Opt[Node]);",
+        concat!(
+            "the struct 'm::Node' has an infinite size, because it contains ",
+            "itself (m::Node.next -> m::Opt.v -> m::Node); consider using a ",
+            "pointer instead"
+        )
+    );
+}
+
+#[test]
+fn template_argument_through_pointer() {
+    assert_ok(concat!(
+        "mod m; struct Opt[T](v T); struct Pair(y Opt[*Pair]); ",
+        "struct Outer(x Opt[Pair]);"
+    ));
+}
+
+#[test]
+fn growing_template_arguments() {
+    let errors: Vec<String> = errors(
+        "mod m; struct Box[T](v T); struct List[T](next List[Box[T]]);"
+    );
+    assert_eq!(errors.len(), 1, "{:#?}", errors);
+    assert!(errors[0].starts_with("the struct 'm::List' has an infinite size"));
+}
+
+#[test]
+fn mutual_recursion() {
+    let errors: Vec<String> = errors(
+        "mod m; struct A(b B); struct B(a A);"
+    );
+    assert_eq!(errors.len(), 1, "{:#?}", errors);
+}
+
+#[test]
+fn growing_through_template_argument() {
+    let errors: Vec<String> = errors(concat!(
+        "mod m; struct Box[T](v T); struct Wrap[U](u U); ",
+        "struct P[T](x Wrap[P[Box[T]]]);"
+    ));
+    assert_eq!(errors.len(), 1, "{:#?}", errors);
+    assert!(errors[0].contains("has an infinite size"), "{}", errors[0]);
+}
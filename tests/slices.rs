@@ -0,0 +1,122 @@
+mod common;
+
+use common::*;
+
+#[test]
+fn slices_from_arrays() {
+    assert_ok(concat!(
+        "mod m; fun f(a [8]u32, i usize): []u32 {\n",
+        "    const all []u32 = a[..];\n",
+        "    const head []u32 = a[..4];\n",
+        "    const tail []u32 = a[4..];\n",
+        "    return a[i..i + 2];\n",
+        "}\n"
+    ));
+}
+
+#[test]
+fn slices_from_slices_and_pointers() {
+    assert_ok(concat!(
+        "mod m; fun f(s []u32, p *const u8, n usize): []const u8 {\n",
+        "    const t []u32 = s[1..];\n",
+        "    return p[0..n];\n",
+        "}\n"
+    ));
+}
+
+#[test]
+fn slicing_a_pointer_needs_an_end() {
+    assert_error(
+        "mod m; fun f(p *u8): []u8 { return p[2..]; }",
+        "slicing a pointer requires an end index, since its length is unknown"
+    );
+}
+
+#[test]
+fn const_is_kept() {
+    assert_error(
+        "mod m; fun f(p *const u8, n usize): []u8 { return p[0..n]; }",
+        "expected a value of type '[]u8', but got a value of type '[]const u8'"
+    );
+}
+
+#[test]
+fn const_arrays_give_const_slices() {
+    assert_error(
+        concat!(
+            "mod m; fun f() {\n",
+            "    const a [3]u8 = [1, 2, 3];\n",
+            "    const s []u8 = a[..];\n",
+            "}\n"
+        ),
+        "expected a value of type '[]u8', but got a value of type '[]const u8'"
+    );
+    assert_ok(concat!(
+        "mod m; fun f() {\n",
+        "    const a [3]u8 = [1, 2, 3];\n",
+        "    const s []const u8 = a[..];\n",
+        "    var b [3]u8 = [1, 2, 3];\n",
+        "    const t []u8 = b[1..];\n",
+        "    t[0] = 5;\n",
+        "}\n"
+    ));
+}
+
+#[test]
+fn length_is_read_only() {
+    assert_error(
+        "mod m; fun f(s []u8) { s.len = 3; }",
+        "the length of an array or slice cannot be modified"
+    );
+    assert_error(
+        "mod m; fun f() { var a [3]u8 = [1, 2, 3]; a.len = 2; }",
+        "the length of an array or slice cannot be modified"
+    );
+}
+
+#[test]
+fn length_and_indexing() {
+    assert_ok(concat!(
+        "mod m; fun f(s []u16, a [3]u8): usize {\n",
+        "    const x u16 = s[0];\n",
+        "    return s.len + a.len;\n",
+        "}\n"
+    ));
+}
+
+#[test]
+fn bounds_must_be_usize() {
+    assert_error(
+        "mod m; fun f(s []u16, i u32): []u16 { return s[i..]; }",
+        "expected a value of type 'usize', but got a value of type 'u32'"
+    );
+}
+
+#[test]
+fn constant_bounds() {
+    assert_error(
+        "mod m; fun f(a [4]u8): []u8 { return a[1..5]; }",
+        "the index 5 is out of bounds for an array of length 4"
+    );
+    assert_error(
+        "mod m; const n usize = 3; fun f(a [4]u8): []u8 { return a[n..2]; }",
+        "the start index 3 is greater than the end index 2"
+    );
+    assert_ok("mod m; fun f(a [4]u8): []u8 { return a[4..]; }");
+}
+
+#[test]
+fn only_sequences_can_be_sliced() {
+    assert_error(
+        "mod m; fun f(x u64): []u8 { return x[0..1]; }",
+        "a value of type 'u64' cannot be sliced"
+    );
+}
+
+#[test]
+fn slice_sizes() {
+    assert_ok(concat!(
+        "mod m; static_assert(sizeof []u8 == sizeof []u64);\n",
+        "static_assert(sizeof []u8 == 2 * sizeof *u8);\n"
+    ));
+}
@@ -0,0 +1,99 @@
+mod common;
+
+use common::*;
+use tolac::Compiler;
+
+const HEADER: &str = concat!(
+    "mod m; struct Header(magic u32, size u64);\n",
+    "const MAX u32 = 16;\n",
+    "const DOUBLE u32 = MAX * 2;\n"
+);
+
+fn with_header(source: &str) -> String {
+    return format!("{}{}", HEADER, source);
+}
+
+#[test]
+fn passing_assertions() {
+    assert_ok(&with_header(concat!(
+        "static_assert(sizeof Header == 16, \"header must be 16 bytes\");\n",
+        "static_assert(DOUBLE == 2 * MAX);\n",
+        "static_assert(-128s8 < 0s8 && (300 as u8) == 44u8);\n",
+        "fun f(x u32): u32 {\n",
+        "    static_assert(MAX == 16, \"local\");\n",
+        "    return x;\n",
+        "}\n"
+    )));
+}
+
+#[test]
+fn failing_assertions() {
+    assert_error(
+        &with_header("static_assert(sizeof Header == 12, \"wrong size\");"),
+        "wrong size"
+    );
+    assert_error(
+        &with_header("static_assert(DOUBLE < MAX);"),
+        "the static assertion failed"
+    );
+    assert_error(
+        &with_header("fun f() { static_assert(MAX == 15, \"local fails\"); }"),
+        "local fails"
+    );
+}
+
+#[test]
+fn local_constants() {
+    assert_ok(concat!(
+        "mod m; fun f() {\n",
+        "    const l u32 = 4;\n",
+        "    static_assert(l == 4, \"local\");\n",
+        "    const d u32 = l * 2;\n",
+        "    static_assert(d == 8, \"derived\");\n",
+        "}\n"
+    ));
+    assert_error(
+        "mod m; fun f() { const l u32 = 4; static_assert(l == 5, \"local\"); }",
+        "local"
+    );
+    // locals shadow global constants
+    assert_error(
+        "mod m; const l u32 = 4; fun f(l u32) { static_assert(l == 4); }",
+        "the value of this expression is not known at compile time"
+    );
+    assert_error(
+        "mod m; fun f() { var l u32 = 4; static_assert(l == 4); }",
+        "the value of this expression is not known at compile time"
+    );
+}
+
+#[test]
+fn invalid_conditions() {
+    assert_error(
+        &with_header("static_assert(MAX - 17 == 0);"),
+        "the value of this expression does not fit into the type 'u32'"
+    );
+    assert_error(
+        &with_header("static_assert(MAX / (MAX - 16) == 0);"),
+        "the divisor of this division is zero"
+    );
+    assert_error(
+        &with_header("static_assert(MAX);"),
+        "expected a value of type 'bool', but got a value of type 'u32'"
+    );
+}
+
+#[test]
+fn multiple_files() {
+    let mut c: Compiler = Compiler::new();
+    c.parse("a.tola", String::from(concat!(
+        "mod a; pub const N u64 = 4;\n",
+        "static_assert(N == 4, \"first file\");\n"
+    )));
+    let errors: Vec<String> = check(&mut c, concat!(
+        "mod b; use a::*;\n",
+        "static_assert(N * 2 == 8, \"uses imports\");\n",
+        "static_assert(a::N == 5, \"full path\");\n"
+    ));
+    assert_eq!(errors, ["full path"]);
+}
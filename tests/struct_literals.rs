@@ -0,0 +1,91 @@
+mod common;
+
+use common::*;
+
+#[test]
+fn literals() {
+    assert_ok(concat!(
+        "mod m; struct Point(x s32, y s32);\n",
+        "fun f(): Point { return Point { y = 2, x = -1 }; }\n"
+    ));
+}
+
+#[test]
+fn template_literals() {
+    assert_ok(concat!(
+        "mod m; struct Pair[A, B](a A, b B);\n",
+        "fun f(x u8): Pair[u8, u16] { return Pair[u8, u16] { a = x, b = 300 }; }\n",
+        "fun g(p Pair[u8, u16]): u16 { return p.b; }\n",
+        "fun h(p *const Pair[u8, u16]): u8 { return p.a; }\n"
+    ));
+}
+
+#[test]
+fn missing_duplicate_and_unknown_fields() {
+    const SOURCE: &str = concat!(
+        "mod m; struct Point(x s32, y s32, z s32);\n",
+        "fun f(): Point { return Point { x = 1, x = 2, w = 3 }; }\n"
+    );
+    let errors: Vec<String> = errors(SOURCE);
+    assert!(errors.contains(&String::from(
+        "the field 'x' is specified more than once"
+    )));
+    assert!(errors.contains(&String::from(
+        "the struct 'm::Point' has no field 'w'"
+    )));
+    assert!(errors.contains(&String::from(
+        "no value is specified for the fields 'y', 'z' of 'm::Point'"
+    )));
+}
+
+#[test]
+fn only_structs() {
+    assert_error(
+        "mod m; enum E u8 { A } fun f(): u8 { const x E = E { a = 1 }; return 0; }",
+        "only structs can be created using a struct literal"
+    );
+}
+
+#[test]
+fn field_values_are_checked() {
+    assert_error(
+        "mod m; struct Point(x s32, y s32); const p Point = Point { x = 1, y = 1.5 };",
+        "expected a value of type 's32', but got a value of type '<float>'"
+    );
+    assert_error(
+        concat!(
+            "mod m; struct Pair[A, B](a A, b B);\n",
+            "const p Pair[u8, bool] = Pair[u8, bool] { a = 1, b = 2 };"
+        ),
+        "expected a value of type 'bool', but got a value of type '<integer>'"
+    );
+}
+
+#[test]
+fn template_fields_are_typed() {
+    assert_error(
+        concat!(
+            "mod m; struct Pair[A, B](a A, b B);\n",
+            "fun f(p Pair[u8, u16]): u8 { return p.b; }"
+        ),
+        "expected a value of type 'u8', but got a value of type 'u16'"
+    );
+}
+
+#[test]
+fn template_arguments_are_checked() {
+    assert_error(
+        concat!(
+            "mod m; struct Pair[A, B](a A, b B);\n",
+            "const p Pair[u8, u8] = Pair[u8] { a = 1, b = 2 };"
+        ),
+        "'m::Pair' expects 2 template arguments, but got 1"
+    );
+    assert_error(
+        concat!(
+            "mod m; struct Box[T: integer](v T);\n",
+            "fun f(): u8 { const b Box[f32] = Box[f32] { v = 1.0 }; return 0; }"
+        ),
+        "the type 'f32' does not satisfy the constraint 'integer' of the template parameter 'T'"
+    );
+}
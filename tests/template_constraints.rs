@@ -0,0 +1,162 @@
+mod common;
+
+use common::*;
+
+const FIXED: &str = concat!(
+    "mod m; use std::ops::*;\n",
+    "struct Fx(v s32): Add, Ordered;\n",
+    "fun Fx::add(a *const Fx, b Fx): Fx { return Fx { v = a.v + b.v }; }\n",
+    "fun Fx::compare(a *const Fx, b Fx): s32 { return a.v - b.v; }\n",
+    "fun max[T: Ordered](a T, b T): T { if a > b { return a; } return b; }\n",
+    "fun neg[T: integer + Negate](a T): T { return -a; }\n"
+);
+
+fn with_fixed(source: &str) -> String {
+    return format!("{}{}", FIXED, source);
+}
+
+#[test]
+fn inferred_arguments() {
+    assert_ok(&with_fixed(concat!(
+        "fun f(x Fx, s []u16): u32 {\n",
+        "    const a Fx = max(x, x) + max[Fx](x, x);\n",
+        "    const b u32 = max(1, 2u32);\n",
+        "    const c s8 = neg(5s8);\n",
+        "    const d f64 = max(1.5, 2.0);\n",
+        "    return b;\n",
+        "}\n"
+    )));
+    assert_ok(concat!(
+        "mod m;\n",
+        "struct Box[T](v T);\n",
+        "fun Box::get[T](b *const Box[T]): T { return b.v; }\n",
+        "fun first[T](s []T): T { return s[0]; }\n",
+        "fun f(b Box[u16], s []u16): u16 { return b.get() + first(s); }\n"
+    ));
+}
+
+#[test]
+fn inferred_arguments_must_fit() {
+    assert_error(
+        &with_fixed("fun f(): bool { return max(true, false); }"),
+        concat!(
+            "the type 'bool' does not satisfy the constraint ",
+            "'std::ops::Ordered' of the template parameter 'T'"
+        )
+    );
+    assert_error(
+        &with_fixed("fun f(): u8 { return max(1u8, 2u16); }"),
+        "expected a value of type 'u8', but got a value of type 'u16'"
+    );
+    assert_error(
+        concat!(
+            "mod m; use std::ops::*; struct Fx(v s32);\n",
+            "fun max[T: Ordered](a T, b T): T { if a > b { return a; } return b; }\n",
+            "fun f(x Fx): Fx { return max(x, x); }"
+        ),
+        concat!(
+            "the type 'm::Fx' does not satisfy the constraint ",
+            "'std::ops::Ordered' of the template parameter 'T'"
+        )
+    );
+}
+
+#[test]
+fn uninferable_arguments() {
+    assert_error(
+        "mod m; fun make[T](): T { return make[T](); } fun f(): u8 { return make(); }",
+        concat!(
+            "the template argument 'T' of 'm::make' cannot be inferred ",
+            "from the arguments, so it needs to be specified"
+        )
+    );
+    assert_ok("mod m; fun make[T](): T { return make[T](); } fun f(): u8 { return make[u8](); }");
+}
+
+#[test]
+fn operators_in_bodies() {
+    assert_error(
+        "mod m; fun f[T](a T): T { return a + a; }",
+        concat!(
+            "the operator '+' cannot be used on values of type 'T', ",
+            "since it does not implement the interface 'std::ops::Add'"
+        )
+    );
+    assert_error(
+        "mod m; use std::ops::*; fun f[T: Ordered](a T): bool { return a == a; }",
+        concat!(
+            "the operator '==' cannot be used on values of type 'T', ",
+            "since it does not implement the interface 'std::ops::Equal'"
+        )
+    );
+    assert_error(
+        "mod m; fun f[T: integer](a T): T { return -a; }",
+        concat!(
+            "the operator '-' cannot be used on values of type 'T', ",
+            "since it does not implement the interface 'std::ops::Negate'"
+        )
+    );
+    assert_ok(concat!(
+        "mod m; use std::ops::*;\n",
+        "fun f[T: integer](a T, b T): T {\n",
+        "    const c T = a + b * 2 - a / b % b;\n",
+        "    const d T = a & b | ~a ^ (b << 1u8);\n",
+        "    const e bool = a < b && a == b;\n",
+        "    return c + d;\n",
+        "}\n",
+        "fun g[T: float](a T): T { return -a * 2.0 + a % a; }\n",
+        "fun h[T: Add + Ordered](a T, b T): T { if a < b { return a + b; } return b; }\n"
+    ));
+}
+
+#[test]
+fn values_in_bodies() {
+    assert_error(
+        "mod m; fun f[T: integer](a T): u32 { return a; }",
+        "expected a value of type 'u32', but got a value of type 'T'"
+    );
+    assert_error(
+        "mod m; fun f[T](a T): u32 { return a.v; }",
+        "the type 'T' has no field 'v'"
+    );
+    assert_error(
+        "mod m; fun f[T: float](a T): T { return a & a; }",
+        "the operator '&' cannot be used on values of type 'T' and 'T'"
+    );
+}
+
+#[test]
+fn number_operators() {
+    assert_error(
+        &with_fixed("fun f(): u8 { return neg(5u8); }"),
+        concat!(
+            "the type 'u8' does not satisfy the constraint ",
+            "'std::ops::Negate' of the template parameter 'T'"
+        )
+    );
+    assert_error(
+        "mod m; use std::ops::*; fun f[T: Add](a T): T { return a; } fun g(): bool { return f(true); }",
+        concat!(
+            "the type 'bool' does not satisfy the constraint ",
+            "'std::ops::Add' of the template parameter 'T'"
+        )
+    );
+    assert_ok(concat!(
+        "mod m; use std::ops::*;\n",
+        "fun f[T: Equal](a T): bool { return a == a; }\n",
+        "fun g[T: Negate](a T): T { return -a; }\n",
+        "fun h(): bool { return f(true) && f(1u8) && g(1.5f32) == g(1.5f32); }\n"
+    ));
+}
+
+#[test]
+fn struct_arguments() {
+    assert_ok("mod m; struct Box[T: integer](v T); fun f(b Box[u8]): u8 { return b.v; }");
+    assert_error(
+        "mod m; struct Box[T: integer](v T); fun f(b Box[f32]): f32 { return b.v; }",
+        concat!(
+            "the type 'f32' does not satisfy the constraint 'integer' ",
+            "of the template parameter 'T'"
+        )
+    );
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use common::*;
+
+#[test]
+fn multiple_return_values() {
+    assert_ok(concat!(
+        "mod m; fun divmod(a u32, b u32): (u32, u32) {\n",
+        "    return (a / b, a % b);\n",
+        "}\n",
+        "fun f(): u32 {\n",
+        "    var (q, r) = divmod(7, 2);\n",
+        "    const (x, _) = divmod(1, 2);\n",
+        "    q += 1;\n",
+        "    return q + r + x;\n",
+        "}\n"
+    ));
+}
+
+#[test]
+fn element_access() {
+    assert_ok(concat!(
+        "mod m; fun f(p *(u32, (bool, f32))): f32 {\n",
+        "    const t (u32, (bool, f32)) = (1, (true, 2.0));\n",
+        "    const u bool = t.1.0;\n",
+        "    const v u32 = t.0;\n",
+        "    return p.1.1;\n",
+        "}\n"
+    ));
+    assert_error(
+        "mod m; fun f(t (u32, bool)) { const v u32 = t.2; }",
+        "the tuple '(u32, bool)' has no element '2'"
+    );
+    assert_error(
+        "mod m; fun f(t (u32, (bool, f32))) { const v u8 = t.1; }",
+        "expected a value of type 'u8', but got a value of type '(bool, f32)'"
+    );
+}
+
+#[test]
+fn element_types() {
+    assert_error(
+        "mod m; fun f() { const g (u32, bool) = (true, 1); }",
+        "expected a value of type '(u32, bool)', but got a value of type '(bool, <integer>)'"
+    );
+}
+
+#[test]
+fn destructuring() {
+    assert_error(
+        "mod m; fun f(t (u32, bool)) { const (a, b, c) = t; }",
+        "'(u32, bool)' has 2 elements, but 3 names are given"
+    );
+    assert_error(
+        "mod m; fun f() { const (d) = 5; }",
+        "a value of type '<integer>' cannot be destructured"
+    );
+    assert_error(
+        "mod m; fun f(t (u32, bool)) { const (a, b) = t; a = 2; }",
+        "the constant 'a' cannot be modified"
+    );
+}
+
+#[test]
+fn infinite_size() {
+    assert_error(
+        "mod m; struct Rec(t (u32, Rec));",
+        concat!(
+            "the struct 'm::Rec' has an infinite size, because it contains ",
+            "itself (m::Rec.t -> m::Rec); consider using a pointer instead"
+        )
+    );
+}
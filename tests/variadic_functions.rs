@@ -0,0 +1,83 @@
+mod common;
+
+use common::*;
+use tolac::{AstNode, Compiler, NodeType};
+
+const PRINTF: &str = "mod m; ext fun printf(fmt *const u8, ...): s32;\n";
+
+fn find_call(n: &AstNode) -> Option<AstNode> {
+    if n.t == NodeType::Call { return Some(n.clone()); }
+    return n.children.iter().find_map(find_call);
+}
+
+// the types of the arguments passed in the first call in 'm::f'
+fn argument_types(source: &str) -> Vec<String> {
+    let mut c: Compiler = Compiler::new();
+    let errors: Vec<String> = check(&mut c, &format!("{}{}", PRINTF, source));
+    assert!(errors.is_empty(), "unexpected errors: {:#?}", errors);
+    let function: AstNode = c.symbols.symbols().iter()
+        .find(|(p, _)| p.display(&c) == "m::f")
+        .map(|(_, s)| s.decl_node.clone())
+        .expect("should be declared");
+    let call: AstNode = find_call(&function).expect("should call a function");
+    return call.children[1].children.iter()
+        .map(|a| a.rtype.display(&c))
+        .collect();
+}
+
+#[test]
+fn default_promotions() {
+    assert_eq!(
+        argument_types(concat!(
+            "fun f(x u8, y f32, b bool, p *u8, q u64) {\n",
+            "    printf(c\"%d %f %d %s %lu\", x, y, b, p, q);\n",
+            "}\n"
+        )),
+        ["*const u8", "s32", "f64", "s32", "*u8", "u64"]
+    );
+}
+
+#[test]
+fn literals() {
+    assert_eq!(
+        argument_types("fun f() { printf(c\"%d %f %lu\", 5, 1.5, 2u64); }"),
+        ["*const u8", "s32", "f64", "u64"]
+    );
+    assert_error(
+        "mod m; ext fun printf(fmt *const u8, ...): s32; fun f() { printf(c\"%d\", 5000000000); }",
+        "the value 5000000000 does not fit into the type 's32'"
+    );
+}
+
+#[test]
+fn fixed_parameters() {
+    assert_error(
+        &format!("{}fun f() {{ printf(); }}", PRINTF),
+        "'m::printf' expects at least 1 argument, but got 0"
+    );
+    assert_error(
+        &format!("{}fun f() {{ printf(5); }}", PRINTF),
+        "expected a value of type '*const u8', but got a value of type '<integer>'"
+    );
+    assert_ok("mod m; ext fun log(...); fun f() { log(); log(1, true, null); }");
+}
+
+#[test]
+fn invalid_arguments() {
+    assert_error(
+        &format!("{}struct S(a u8);\nfun f(s S) {{ printf(c\"%d\", s); }}", PRINTF),
+        "a value of type 'm::S' cannot be passed as a variadic argument"
+    );
+    assert_error(
+        &format!("{}fun f() {{ printf(c\"%d\", (1, 2)); }}", PRINTF),
+        "a value of type '(<integer>, <integer>)' cannot be passed as a variadic argument"
+    );
+}
+
+#[test]
+fn declarations() {
+    assert_error(
+        "mod m; fun printf(fmt *const u8, ...): s32 { return 0; }",
+        "only external functions can take a variable number of arguments"
+    );
+}